@@ -0,0 +1,111 @@
+//! Single-use action tokens for email verification and password reset
+//!
+//! Backs both `POST /api/v1/auth/verify/request` /
+//! `GET /api/v1/auth/verify/confirm` and
+//! `POST /api/v1/auth/password/reset-request` /
+//! `POST /api/v1/auth/password/reset-confirm`. Each is the same shape: mint
+//! a random 256-bit value, store only its salted hash bound to a user and a
+//! purpose, and accept it back exactly once within a ~1h window - so this
+//! lives as one generic store rather than two near-identical ones (mirrors
+//! how [`crate::refresh_token::RefreshTokenStore`] hashes before storing).
+
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+use uuid::Uuid;
+
+/// What an action token authorizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActionTokenPurpose {
+    EmailVerification,
+    PasswordReset,
+}
+
+const ACTION_TOKEN_TTL: Duration = Duration::from_secs(60 * 60);
+
+struct ActionTokenRecord {
+    user_id: Uuid,
+    purpose: ActionTokenPurpose,
+    expires_at: SystemTime,
+}
+
+/// Tracks live action tokens by the salted hash of their value.
+#[derive(Default)]
+pub struct ActionTokenStore {
+    tokens: Mutex<HashMap<String, ActionTokenRecord>>,
+    /// Per-deployment salt mixed into every hash, so the store's hashes
+    /// aren't directly comparable to a generic SHA-256 rainbow table.
+    salt: String,
+}
+
+/// Why a presented action token was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionTokenError {
+    /// Unknown, already-consumed, forged, or wrong-purpose token.
+    Invalid,
+    Expired,
+}
+
+impl ActionTokenStore {
+    pub fn new(salt: String) -> Self {
+        ActionTokenStore {
+            tokens: Mutex::new(HashMap::new()),
+            salt,
+        }
+    }
+
+    /// Mints a token for `user_id`/`purpose`, returning the value to embed
+    /// in the verification/reset link. Only the hash is retained.
+    pub fn issue(&self, user_id: Uuid, purpose: ActionTokenPurpose) -> String {
+        let token = random_token();
+        let hash = self.hash_token(&token);
+        self.tokens.lock().expect("action token store poisoned").insert(
+            hash,
+            ActionTokenRecord {
+                user_id,
+                purpose,
+                expires_at: SystemTime::now() + ACTION_TOKEN_TTL,
+            },
+        );
+        token
+    }
+
+    /// Consumes `presented_token` if it's valid for `purpose`, returning the
+    /// bound `user_id`. Single-use: the entry is removed whether or not it
+    /// turns out to be valid, so a replay after a failed attempt also fails.
+    pub fn consume(
+        &self,
+        presented_token: &str,
+        purpose: ActionTokenPurpose,
+    ) -> Result<Uuid, ActionTokenError> {
+        let hash = self.hash_token(presented_token);
+        let record = {
+            let mut tokens = self.tokens.lock().expect("action token store poisoned");
+            tokens.remove(&hash).ok_or(ActionTokenError::Invalid)?
+        };
+
+        if record.purpose != purpose {
+            return Err(ActionTokenError::Invalid);
+        }
+        if record.expires_at < SystemTime::now() {
+            return Err(ActionTokenError::Expired);
+        }
+
+        Ok(record.user_id)
+    }
+
+    fn hash_token(&self, token: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.salt.as_bytes());
+        hasher.update(token.as_bytes());
+        hex::encode(hasher.finalize())
+    }
+}
+
+fn random_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
@@ -0,0 +1,298 @@
+//! Macaroon-based scoped capability tokens
+//!
+//! An alternative to flat HMAC [`Claims`](super::Claims) JWTs: a macaroon
+//! carries a chain of caveats that *attenuate* what the bearer can do, and
+//! the chain can be extended by the holder offline - no server round trip
+//! needed to mint a strictly weaker token from one you already hold.
+//!
+//! # How the signature chain works
+//!
+//! ```text
+//! sig0     = HMAC(root_secret, identifier)
+//! sig_i    = HMAC(sig_{i-1}, caveat_i)
+//! ```
+//!
+//! The final `sig_n` is what travels in the `Authorization` header alongside
+//! the identifier and the ordered caveat list. Verification recomputes the
+//! same chain from the root secret over the presented caveats; if the
+//! recomputed signature doesn't match, or any caveat's predicate fails
+//! against the request context, the macaroon is rejected.
+//!
+//! Because attenuation only ever *adds* caveats and each link depends on
+//! every caveat before it, a holder can derive a read-only, game-scoped, or
+//! time-boxed macaroon from their own token without the server's
+//! involvement, but can never strip a caveat back off - the chain can only
+//! get weaker, never stronger.
+//!
+//! # Example
+//!
+//! ```
+//! use blackjack_api::auth::macaroon::{Caveat, Macaroon, RequestContext};
+//!
+//! let root_key = b"server-root-secret";
+//! let token = Macaroon::mint(root_key, "player-identifier".to_string())
+//!     .attenuate(root_key, Caveat::GameId("550e8400-e29b-41d4-a716-446655440000".into()))
+//!     .attenuate(root_key, Caveat::Scope(Scope::ViewOnly));
+//! # use blackjack_api::auth::macaroon::Scope;
+//! ```
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// What a macaroon's bearer is allowed to do once its caveats are satisfied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Scope {
+    /// Full gameplay access: draw, set ace value, stand, finish.
+    Play,
+    /// Read-only access: `get_game_state` and the WebSocket feed only.
+    ViewOnly,
+}
+
+/// A single attenuating predicate appended to a macaroon's caveat chain.
+///
+/// Caveats are additive and conjunctive - a macaroon is valid only if every
+/// caveat in its chain holds against the current request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Caveat {
+    /// Restricts the macaroon to a single game.
+    GameId(String),
+    /// Restricts what actions the macaroon authorizes.
+    Scope(Scope),
+    /// Restricts the macaroon to before a Unix timestamp.
+    ExpiresBefore(usize),
+}
+
+impl Caveat {
+    /// Checks this caveat's predicate against `ctx`.
+    fn is_satisfied(&self, ctx: &RequestContext) -> bool {
+        match self {
+            Caveat::GameId(expected) => *expected == ctx.game_id,
+            // Satisfied iff the request asks for no more than what this
+            // caveat grants: `Play` grants everything, `ViewOnly` grants
+            // only `ViewOnly` requests. Getting this backwards would let a
+            // macaroon attenuated down to `ViewOnly` satisfy a `Play`
+            // request - a strictly weaker token authorizing a stronger
+            // action.
+            Caveat::Scope(granted) => match (granted, ctx.requested_scope) {
+                (Scope::Play, _) => true,
+                (Scope::ViewOnly, Scope::ViewOnly) => true,
+                (Scope::ViewOnly, Scope::Play) => false,
+            },
+            Caveat::ExpiresBefore(deadline) => ctx.now <= *deadline,
+        }
+    }
+
+    /// Stable byte encoding fed into the HMAC chain for this caveat.
+    ///
+    /// Must be unambiguous and order-sensitive between caveat kinds, which a
+    /// plain `Debug` string already gives us here.
+    fn chain_bytes(&self) -> Vec<u8> {
+        format!("{:?}", self).into_bytes()
+    }
+}
+
+/// Context a verifier checks a macaroon's caveats against.
+pub struct RequestContext {
+    pub game_id: String,
+    pub requested_scope: Scope,
+    pub now: usize,
+}
+
+/// Why a presented macaroon was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyError {
+    /// The recomputed HMAC chain doesn't match the presented signature -
+    /// the macaroon was forged, corrupted, or minted with a different root
+    /// key.
+    BadSignature,
+    /// A caveat's predicate failed against the request context.
+    CaveatFailed(Caveat),
+}
+
+/// An attenuable, self-contained capability token.
+///
+/// `identifier` is an opaque label (typically the player's email or user
+/// id) that seeds the signature chain; it carries no authority on its own
+/// and is not a caveat.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Macaroon {
+    pub identifier: String,
+    pub caveats: Vec<Caveat>,
+    pub signature: String,
+}
+
+impl Macaroon {
+    /// Mints a root macaroon (no caveats yet) bound to `identifier`.
+    ///
+    /// `sig0 = HMAC(root_key, identifier)`.
+    pub fn mint(root_key: &[u8], identifier: String) -> Self {
+        let signature = hmac_hex(root_key, identifier.as_bytes());
+        Macaroon {
+            identifier,
+            caveats: Vec::new(),
+            signature,
+        }
+    }
+
+    /// Appends `caveat`, advancing the signature chain.
+    ///
+    /// `sig_i = HMAC(sig_{i-1}, caveat_i)`. Takes `root_key` only to mirror
+    /// the mint/attenuate/verify trio's signature; it is ignored. The chain
+    /// is keyed off the previous `signature`, not the root, which is what
+    /// lets a holder who doesn't know the root key attenuate offline.
+    pub fn attenuate(&self, _root_key: &[u8], caveat: Caveat) -> Self {
+        let next_signature = hmac_hex(self.signature.as_bytes(), &caveat.chain_bytes());
+        let mut caveats = self.caveats.clone();
+        caveats.push(caveat);
+        Macaroon {
+            identifier: self.identifier.clone(),
+            caveats,
+            signature: next_signature,
+        }
+    }
+
+    /// Recomputes the HMAC chain from `root_key` and checks every caveat
+    /// against `ctx`.
+    ///
+    /// Fails closed: the first caveat that doesn't hold is returned,
+    /// without checking the rest.
+    pub fn verify(&self, root_key: &[u8], ctx: &RequestContext) -> Result<(), VerifyError> {
+        let mut signature = hmac_hex(root_key, self.identifier.as_bytes());
+        for caveat in &self.caveats {
+            signature = hmac_hex(signature.as_bytes(), &caveat.chain_bytes());
+        }
+
+        if !constant_time_eq(signature.as_bytes(), self.signature.as_bytes()) {
+            return Err(VerifyError::BadSignature);
+        }
+
+        for caveat in &self.caveats {
+            if !caveat.is_satisfied(ctx) {
+                return Err(VerifyError::CaveatFailed(caveat.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOT_KEY: &[u8] = b"test-root-secret";
+
+    fn ctx(game_id: &str, requested_scope: Scope) -> RequestContext {
+        RequestContext {
+            game_id: game_id.to_string(),
+            requested_scope,
+            now: 1_000,
+        }
+    }
+
+    #[test]
+    fn root_macaroon_verifies_with_no_caveats() {
+        let mac = Macaroon::mint(ROOT_KEY, "player@example.com".to_string());
+        assert_eq!(mac.verify(ROOT_KEY, &ctx("any-game", Scope::Play)), Ok(()));
+    }
+
+    #[test]
+    fn wrong_root_key_fails_verification() {
+        let mac = Macaroon::mint(ROOT_KEY, "player@example.com".to_string());
+        assert_eq!(
+            mac.verify(b"different-key", &ctx("any-game", Scope::Play)),
+            Err(VerifyError::BadSignature)
+        );
+    }
+
+    #[test]
+    fn game_id_caveat_restricts_to_that_game() {
+        let mac = Macaroon::mint(ROOT_KEY, "player@example.com".to_string())
+            .attenuate(ROOT_KEY, Caveat::GameId("game-1".to_string()));
+
+        assert_eq!(mac.verify(ROOT_KEY, &ctx("game-1", Scope::Play)), Ok(()));
+        assert_eq!(
+            mac.verify(ROOT_KEY, &ctx("game-2", Scope::Play)),
+            Err(VerifyError::CaveatFailed(Caveat::GameId("game-1".to_string())))
+        );
+    }
+
+    #[test]
+    fn view_only_caveat_does_not_authorize_play() {
+        let mac = Macaroon::mint(ROOT_KEY, "spectator@example.com".to_string())
+            .attenuate(ROOT_KEY, Caveat::Scope(Scope::ViewOnly));
+
+        assert_eq!(mac.verify(ROOT_KEY, &ctx("any-game", Scope::ViewOnly)), Ok(()));
+        assert_eq!(
+            mac.verify(ROOT_KEY, &ctx("any-game", Scope::Play)),
+            Err(VerifyError::CaveatFailed(Caveat::Scope(Scope::ViewOnly)))
+        );
+    }
+
+    #[test]
+    fn play_caveat_authorizes_view_only_requests_too() {
+        let mac = Macaroon::mint(ROOT_KEY, "player@example.com".to_string())
+            .attenuate(ROOT_KEY, Caveat::Scope(Scope::Play));
+
+        assert_eq!(mac.verify(ROOT_KEY, &ctx("any-game", Scope::ViewOnly)), Ok(()));
+        assert_eq!(mac.verify(ROOT_KEY, &ctx("any-game", Scope::Play)), Ok(()));
+    }
+
+    #[test]
+    fn expires_before_caveat_rejects_once_deadline_passes() {
+        let mac = Macaroon::mint(ROOT_KEY, "player@example.com".to_string())
+            .attenuate(ROOT_KEY, Caveat::ExpiresBefore(500));
+
+        let mut past_deadline = ctx("any-game", Scope::Play);
+        past_deadline.now = 1_000;
+
+        assert_eq!(
+            mac.verify(ROOT_KEY, &past_deadline),
+            Err(VerifyError::CaveatFailed(Caveat::ExpiresBefore(500)))
+        );
+    }
+
+    #[test]
+    fn tampering_with_a_caveat_invalidates_the_signature() {
+        let mac = Macaroon::mint(ROOT_KEY, "player@example.com".to_string())
+            .attenuate(ROOT_KEY, Caveat::GameId("game-1".to_string()));
+
+        let mut tampered = mac;
+        tampered.caveats = vec![Caveat::GameId("game-2".to_string())];
+
+        assert_eq!(
+            tampered.verify(ROOT_KEY, &ctx("game-2", Scope::Play)),
+            Err(VerifyError::BadSignature)
+        );
+    }
+
+    #[test]
+    fn constant_time_eq_matches_equal_and_unequal_bytes() {
+        assert!(constant_time_eq(b"abc", b"abc"));
+        assert!(!constant_time_eq(b"abc", b"abd"));
+        assert!(!constant_time_eq(b"abc", b"ab"));
+    }
+}
+
+fn hmac_hex(key: &[u8], message: &[u8]) -> String {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Compares two byte strings in constant time with respect to their
+/// contents, so a mismatch in `verify` can't be timed to learn which byte of
+/// the signature was wrong.
+///
+/// A length mismatch is returned immediately - `verify` always compares two
+/// fixed-length hex-encoded HMAC-SHA256 digests, so length alone leaks
+/// nothing an attacker doesn't already know.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
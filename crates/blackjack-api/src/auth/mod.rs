@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+pub mod macaroon;
+
+/// JWT (JSON Web Token) claims structure
+///
+/// This structure represents the payload of a JWT token used for authenticating
+/// users of the Blackjack API.
+///
+/// # Security
+///
+/// - Tokens are signed using HMAC-SHA256 with a secret key (configured in `AppConfig`)
+/// - The `exp` field enforces automatic token expiration
+/// - Tokens are validated on every protected endpoint request
+///
+/// # Token Lifecycle (M7 account-based auth)
+///
+/// 1. User authenticates via `POST /api/v1/auth/login` with email and password
+/// 2. Server verifies the Argon2id password hash (see [`crate::password`])
+/// 3. Server generates a short-lived access token (~15 min) with these
+///    claims, plus an opaque refresh token (see [`crate::refresh_token`])
+/// 4. Client includes the access token in `Authorization: Bearer <token>`
+/// 5. Middleware validates the token, checks `jti` against
+///    `AppState::revoked_tokens`, and extracts claims for each request
+/// 6. Client calls `POST /api/v1/auth/refresh` with the refresh token to
+///    mint a new access token once the old one expires, without re-entering
+///    credentials
+///
+/// Unlike the original per-game token, an access token is scoped to the
+/// user's account rather than a single game - `game_id` is carried in the
+/// request path instead, and handlers confirm the caller is actually a
+/// member of that game via `GameService`.
+///
+/// # Example
+///
+/// ```
+/// use blackjack_api::auth::Claims;
+///
+/// let claims = Claims {
+///     user_id: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+///     email: "player@example.com".to_string(),
+///     jti: uuid::Uuid::new_v4().to_string(),
+///     exp: 1704672000, // Unix timestamp
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Claims {
+    /// Authenticated user's account UUID, as a string.
+    pub user_id: String,
+
+    /// User's email address
+    ///
+    /// Used to:
+    /// - Look up the player's state in game operations
+    /// - Form the rate limiting key: `{game_id}:{email}`
+    /// - Ensure players can only act on their own behalf
+    pub email: String,
+
+    /// Unique token identifier ("JWT ID")
+    ///
+    /// Minted fresh for every access token issued by `login` or `refresh`.
+    /// The auth middleware checks this against `AppState::revoked_tokens`
+    /// so a logged-out or leaked token can be invalidated before its `exp`
+    /// passes, which a stateless JWT otherwise can't support.
+    pub jti: String,
+
+    /// Token expiration time as Unix timestamp (seconds since epoch)
+    ///
+    /// The JWT library automatically validates this field. Once the current time
+    /// exceeds this timestamp, the token is considered invalid and authentication
+    /// will fail with a 401 error.
+    ///
+    /// Example: 1704672000 represents January 8, 2024, 00:00:00 UTC
+    pub exp: usize,
+}
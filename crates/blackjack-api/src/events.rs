@@ -0,0 +1,140 @@
+//! App-wide notification stream for invitations and gameplay turns
+//!
+//! `websocket::GameEvent` pushes the full state of a *single* game to
+//! clients already watching it. This module is the complement: a single
+//! app-wide bus that `create_invitation`, `accept_invitation`,
+//! `decline_invitation`, and `stand` publish onto, and that a user
+//! subscribes to once (via SSE at `GET /api/v1/events`) to hear about
+//! invitations addressed to them and turns in any game they're playing,
+//! without polling `get_pending_invitations` or a game's state.
+//!
+//! # Endpoint
+//!
+//! `GET /api/v1/events`
+//!
+//! # Filtering
+//!
+//! Every subscriber gets a fresh receiver on the same broadcast channel, so
+//! filtering happens per-connection in `events_stream`: an event passes
+//! through if its `target_email` matches the caller, or its `game_id` names
+//! a game the caller is currently a player in.
+//!
+//! # Lagging
+//!
+//! A slow subscriber that falls behind the channel's capacity doesn't get
+//! disconnected; `events_stream` turns a `Lagged` error into a synthetic
+//! `{"kind":"resync"}` event so the client knows to re-fetch state instead
+//! of silently missing updates.
+
+use std::convert::Infallible;
+
+use axum::extract::State;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::Extension;
+use futures_util::stream::{Stream, StreamExt};
+use serde::Serialize;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+use uuid::Uuid;
+
+use crate::auth::Claims;
+use crate::AppState;
+
+/// Maximum number of buffered notifications before a slow subscriber starts
+/// missing events (mirrors `websocket::GAME_EVENT_CHANNEL_CAPACITY`).
+pub const NOTIFICATION_CHANNEL_CAPACITY: usize = 256;
+
+/// What kind of thing happened, tagged so clients can dispatch on `kind`
+/// without inspecting `payload`.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    InvitationReceived,
+    InvitationAccepted,
+    InvitationDeclined,
+    InvitationRevoked,
+    PlayerStood,
+    TurnAdvanced,
+    GameFinished,
+}
+
+/// A single notification fanned out on the app-wide bus.
+///
+/// `target_email` and `game_id` are the two ways a subscriber's filter can
+/// match: an invitation event targets a specific email, a gameplay event
+/// targets a game's current players.
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationEvent {
+    pub kind: NotificationKind,
+    pub target_email: Option<String>,
+    pub game_id: Option<Uuid>,
+    pub payload: serde_json::Value,
+}
+
+/// Publishes `event` on `state`'s notification bus.
+///
+/// Like `websocket::GameBroadcastRegistry::publish`, returns without error
+/// when nobody is currently subscribed - `SendError` there only means no
+/// receivers exist yet, which isn't a failure from the caller's side.
+pub fn publish(state: &AppState, event: NotificationEvent) {
+    let _ = state.notifications.send(event);
+}
+
+/// Streams notifications addressed to the authenticated user as
+/// Server-Sent Events.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/events`
+///
+/// # Authentication
+///
+/// **Required** - Must include valid JWT token in Authorization header.
+#[tracing::instrument(skip(state))]
+pub async fn events_stream(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let receiver = state.notifications.subscribe();
+    let email = claims.email.clone();
+    let game_service = state.game_service.clone();
+
+    let stream = BroadcastStream::new(receiver).filter_map(move |item| {
+        let email = email.clone();
+        let game_service = game_service.clone();
+        async move {
+            let event = match item {
+                Ok(event) => event,
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "SSE client lagged, events dropped");
+                    return Some(Ok(sse_event(&serde_json::json!({ "kind": "resync" }))));
+                }
+            };
+
+            let is_for_me = event.target_email.as_deref() == Some(email.as_str());
+            let is_my_game = event
+                .game_id
+                .map(|game_id| {
+                    game_service
+                        .get_game_state(game_id)
+                        .map(|state| state.players.contains_key(&email))
+                        .unwrap_or(false)
+                })
+                .unwrap_or(false);
+
+            if !is_for_me && !is_my_game {
+                return None;
+            }
+
+            Some(Ok(sse_event(&event)))
+        }
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+fn sse_event(payload: &impl Serialize) -> Event {
+    Event::default()
+        .json_data(payload)
+        .unwrap_or_else(|_| Event::default().data("{\"kind\":\"resync\"}"))
+}
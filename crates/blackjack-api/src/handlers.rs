@@ -32,6 +32,8 @@
 
 use crate::auth::Claims;
 use crate::error::ApiError;
+use crate::events::{self, NotificationEvent, NotificationKind};
+use crate::websocket::{GameEvent, GameEventKind};
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::{Extension, Json};
@@ -85,17 +87,25 @@ pub struct LoginRequest {
 /// ```
 #[derive(Debug, Serialize)]
 pub struct LoginResponse {
-    /// JWT token for authentication
+    /// Short-lived JWT access token for authentication
     ///
     /// This token should be included in the Authorization header:
     /// `Authorization: Bearer <token>`
     pub token: String,
-    
-    /// Token expiration time in seconds
+
+    /// Access token expiration time in seconds
     ///
-    /// Calculated as `expiration_hours * 3600`
-    /// Default: 86400 (24 hours)
+    /// Calculated as `access_token_expiration_minutes * 60`.
+    /// Default: 900 (15 minutes).
     pub expires_in: u64,
+
+    /// Opaque refresh token
+    ///
+    /// Exchange this at `POST /api/v1/auth/refresh` for a new access token
+    /// once the current one expires, without re-entering credentials. Store
+    /// it as securely as the access token - presenting it is sufficient to
+    /// mint new access tokens for this account.
+    pub refresh_token: String,
 }
 
 /// Authenticates a player for a game session
@@ -197,21 +207,67 @@ pub async fn login(
     State(state): State<crate::AppState>,
     Json(payload): Json<LoginRequest>,
 ) -> Result<Json<LoginResponse>, ApiError> {
-    // Authenticate user with UserService
-    let user = state.user_service.login(&payload.email, &payload.password)?;
-    
-    // Calculate expiration time
+    // Authenticate user with UserService. Unknown email and wrong password
+    // are both surfaced as the same INVALID_CREDENTIALS error so a failed
+    // attempt doesn't tell the caller which one was wrong.
+    let user = state
+        .user_service
+        .login(&payload.email, &payload.password)
+        .map_err(|err| {
+            tracing::warn!(email = %payload.email, error = ?err, "Login failed");
+            ApiError::new(
+                StatusCode::UNAUTHORIZED,
+                "INVALID_CREDENTIALS",
+                "Invalid email or password",
+            )
+        })?;
+
+    if state.config.auth.require_verified_email && !user.verified {
+        return Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "EMAIL_NOT_VERIFIED",
+            "Please verify your email before logging in",
+        ));
+    }
+
+    let (token, expires_in) = issue_access_token(&state, user.id, &user.email)?;
+
+    // Opaque refresh token, starting a fresh family for this login. `logout`
+    // revokes the whole family, and `refresh` rotates it one token at a time.
+    let issued_refresh = state.refresh_tokens.issue(user.id);
+
+    tracing::info!(
+        user_id = %user.id,
+        email = %user.email,
+        "User authenticated successfully"
+    );
+
+    Ok(Json(LoginResponse {
+        token,
+        expires_in,
+        refresh_token: issued_refresh.token,
+    }))
+}
+
+/// Mints a signed, short-lived access token for `user_id`/`email`.
+///
+/// Shared by `login` and `refresh` so both paths generate claims the exact
+/// same way - a fresh `jti`, `access_token_expiration_minutes` from now.
+pub(crate) fn issue_access_token(
+    state: &crate::AppState,
+    user_id: Uuid,
+    email: &str,
+) -> Result<(String, u64), ApiError> {
     let expiration = chrono::Utc::now()
-        + chrono::Duration::hours(state.config.jwt.expiration_hours as i64);
+        + chrono::Duration::minutes(state.config.jwt.access_token_expiration_minutes as i64);
 
-    // Generate JWT claims
     let claims = Claims {
-        user_id: user.id.to_string(),
-        email: user.email.clone(),
+        user_id: user_id.to_string(),
+        email: email.to_string(),
+        jti: Uuid::new_v4().to_string(),
         exp: expiration.timestamp() as usize,
     };
 
-    // Generate JWT token
     let token = encode(
         &Header::default(),
         &claims,
@@ -226,15 +282,125 @@ pub async fn login(
         )
     })?;
 
-    tracing::info!(
-        user_id = %user.id,
-        email = %user.email,
-        "User authenticated successfully"
-    );
+    Ok((
+        token,
+        state.config.jwt.access_token_expiration_minutes * 60,
+    ))
+}
+
+/// Request to exchange a refresh token for a new access token
+#[derive(Debug, Deserialize)]
+pub struct RefreshRequest {
+    /// The opaque refresh token issued by `login` or a previous `refresh`
+    pub refresh_token: String,
+}
+
+/// Exchanges a refresh token for a new access token, rotating the refresh
+/// token in the process
+///
+/// # Endpoint
+///
+/// `POST /api/v1/auth/refresh`
+///
+/// # Authentication
+///
+/// None beyond the refresh token itself - this is how a client re-obtains
+/// an access token after the short-lived one expires.
+///
+/// # Errors
+///
+/// - **401 Unauthorized** - unknown, already-consumed, or expired refresh
+///   token (`REFRESH_TOKEN_INVALID` / `REFRESH_TOKEN_EXPIRED`)
+#[tracing::instrument(skip(state, payload))]
+pub async fn refresh(
+    State(state): State<crate::AppState>,
+    Json(payload): Json<RefreshRequest>,
+) -> Result<Json<LoginResponse>, ApiError> {
+    let (user_id, issued_refresh) = state
+        .refresh_tokens
+        .rotate(&payload.refresh_token)
+        .map_err(|err| match err {
+            crate::refresh_token::RefreshTokenError::Invalid => ApiError::new(
+                StatusCode::UNAUTHORIZED,
+                "REFRESH_TOKEN_INVALID",
+                "Refresh token is invalid or has already been used",
+            ),
+            crate::refresh_token::RefreshTokenError::Expired => ApiError::new(
+                StatusCode::UNAUTHORIZED,
+                "REFRESH_TOKEN_EXPIRED",
+                "Refresh token has expired; please log in again",
+            ),
+        })?;
+
+    let user = state.user_service.get_user(user_id)?;
+    let (token, expires_in) = issue_access_token(&state, user.id, &user.email)?;
+
+    tracing::info!(user_id = %user.id, "Access token refreshed");
 
     Ok(Json(LoginResponse {
         token,
-        expires_in: state.config.jwt.expiration_hours * 3600,
+        expires_in,
+        refresh_token: issued_refresh.token,
+    }))
+}
+
+/// Response for a successful logout
+#[derive(Debug, Serialize)]
+pub struct LogoutResponse {
+    /// Success message
+    pub message: String,
+}
+
+/// Optional body for `POST /api/v1/auth/logout`
+#[derive(Debug, Deserialize, Default)]
+pub struct LogoutRequest {
+    /// The refresh token issued alongside the access token being logged
+    /// out. When present, its entire refresh-token family is revoked so no
+    /// descendant of this login session can mint new access tokens either.
+    pub refresh_token: Option<String>,
+}
+
+/// Logs out the authenticated player by revoking their current token
+///
+/// A JWT is otherwise valid until its `exp`, so logging out wouldn't
+/// actually do anything without server-side state. This handler adds the
+/// presented access token's `jti` to `AppState::revoked_tokens` (the auth
+/// middleware rejects any later request bearing that `jti`, even though the
+/// token itself is still cryptographically valid), and, if a refresh token
+/// is included in the body, revokes its whole family via
+/// `AppState::refresh_tokens` so the client can't silently mint a
+/// replacement access token afterward.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/auth/logout`
+///
+/// # Authentication
+///
+/// **Required** - the token being logged out must itself be valid and
+/// unrevoked at the time of the call.
+///
+/// # Errors
+///
+/// - **401 Unauthorized** - missing or invalid JWT token
+#[tracing::instrument(skip(state, payload), fields(email = %claims.email))]
+pub async fn logout(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    payload: Option<Json<LogoutRequest>>,
+) -> Result<Json<LogoutResponse>, ApiError> {
+    state.revoked_tokens.revoke(claims.jti.clone(), claims.exp);
+
+    if let Some(Json(LogoutRequest { refresh_token: Some(refresh_token) })) = payload {
+        if let Some(family_id) = state.refresh_tokens.family_of(&refresh_token) {
+            state.refresh_tokens.revoke_family(family_id);
+        }
+    }
+
+    tracing::info!(email = %claims.email, jti = %claims.jti, "Token revoked on logout");
+
+    Ok(Json(LogoutResponse {
+        message: "Logged out successfully".to_string(),
     }))
 }
 
@@ -345,7 +511,6 @@ pub async fn health_check() -> Json<HealthResponse> {
 /// # Future Enhancements
 ///
 /// In future versions, this endpoint will include:
-/// - Database connection check (SQLite)
 /// - Metrics system availability
 /// - External service dependencies
 ///
@@ -354,20 +519,60 @@ pub async fn health_check() -> Json<HealthResponse> {
 /// ```bash
 /// curl http://localhost:8080/health/ready
 /// ```
-#[tracing::instrument]
-pub async fn ready_check() -> Json<ReadyResponse> {
+#[tracing::instrument(skip(state))]
+pub async fn ready_check(State(state): State<crate::AppState>) -> Json<ReadyResponse> {
     let mut checks = HashMap::new();
     checks.insert("memory".to_string(), "ok".to_string());
     checks.insert("config".to_string(), "loaded".to_string());
-    checks.insert("future_sqlite".to_string(), "pending".to_string());
+
+    // Real connectivity probe (a `SELECT 1` for the SQLite backend, a no-op
+    // for the in-memory one) rather than the hardcoded "pending" this used
+    // to report.
+    let sqlite_status = match state.repository.ping().await {
+        Ok(()) => "ok",
+        Err(_) => "unreachable",
+    };
+    checks.insert("sqlite".to_string(), sqlite_status.to_string());
     checks.insert("future_metrics".to_string(), "pending".to_string());
 
     Json(ReadyResponse {
-        ready: true,
+        ready: sqlite_status == "ok",
         checks,
     })
 }
 
+/// Fans a game-state change out to any connected WebSocket clients.
+///
+/// Fetches the latest state directly from `game_service` rather than
+/// threading the action's own response through, so every subscriber always
+/// sees the authoritative post-action view regardless of which handler
+/// triggered it. Silently does nothing if the state lookup fails (the HTTP
+/// response to the acting player already carries that error).
+fn publish_game_event(
+    state: &crate::AppState,
+    game_id: Uuid,
+    kind: GameEventKind,
+    actor_email: Option<String>,
+) {
+    if !state.config.websocket.enabled {
+        return;
+    }
+
+    match state.game_service.get_game_state(game_id) {
+        Ok(game_state) => state.game_broadcast.publish(
+            game_id,
+            GameEvent {
+                kind,
+                actor_email,
+                state: game_state,
+            },
+        ),
+        Err(err) => {
+            tracing::warn!(game_id = %game_id, error = ?err, "Failed to fetch game state for WebSocket broadcast");
+        }
+    }
+}
+
 // ============================================================================
 // Game Management Endpoints
 // ============================================================================
@@ -491,9 +696,33 @@ pub async fn create_game(
     // Create game via service
     // TODO M7: Update to require authentication and use user_id as creator_id
     // For backward compatibility, use a placeholder UUID
+    //
+    // `games.creator_id` has no foreign key into `users` (see migration
+    // 0002) precisely because this placeholder doesn't correspond to a real
+    // account until that TODO lands.
     let creator_id = Uuid::new_v4(); // Temporary placeholder
+    let emails = payload.emails.clone();
     let game_id = state.game_service.create_game(creator_id, payload.emails)?;
 
+    if let Err(err) = state.repository.create_game(game_id, creator_id).await {
+        tracing::warn!(game_id = %game_id, error = ?err, "Failed to persist new game row");
+    }
+    for email in &emails {
+        if let Err(err) = state
+            .repository
+            .upsert_player(crate::persistence::StoredPlayer {
+                game_id,
+                email: email.clone(),
+                points: 0,
+                busted: false,
+                standing: false,
+            })
+            .await
+        {
+            tracing::warn!(game_id = %game_id, email = %email, error = ?err, "Failed to persist initial player row");
+        }
+    }
+
     tracing::info!(
         game_id = %game_id,
         player_count = player_count,
@@ -668,6 +897,13 @@ pub async fn draw_card(
 ) -> Result<Json<DrawCardResponse>, ApiError> {
     // Validate it's the player's turn
     let game_state = state.game_service.get_game_state(game_id)?;
+    if !game_state.started {
+        return Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "GAME_NOT_STARTED",
+            "Game has not started yet",
+        ));
+    }
     if let Some(current_player) = game_state.current_turn_player {
         if current_player != claims.email {
             return Err(ApiError::new(
@@ -680,6 +916,36 @@ pub async fn draw_card(
 
     let response = state.game_service.draw_card(game_id, &claims.email)?;
 
+    if let Err(err) = state
+        .repository
+        .record_card_draw(crate::persistence::StoredCardDraw {
+            game_id,
+            player_email: claims.email.clone(),
+            card_id: response.card.id.to_string(),
+            card_name: response.card.name.clone(),
+            card_value: response.card.value as i64,
+            card_suit: response.card.suit.clone(),
+        })
+        .await
+    {
+        tracing::warn!(game_id = %game_id, email = %claims.email, error = ?err, "Failed to persist drawn card");
+    }
+    if let Err(err) = state
+        .repository
+        .upsert_player(crate::persistence::StoredPlayer {
+            game_id,
+            email: claims.email.clone(),
+            points: response.current_points as i64,
+            busted: response.busted,
+            standing: false,
+        })
+        .await
+    {
+        tracing::warn!(game_id = %game_id, email = %claims.email, error = ?err, "Failed to persist player row after draw");
+    }
+
+    publish_game_event(&state, game_id, GameEventKind::CardDrawn, Some(claims.email.clone()));
+
     Ok(Json(response))
 }
 
@@ -759,6 +1025,8 @@ pub async fn set_ace_value(
         .game_service
         .set_ace_value(game_id, &claims.email, payload.card_id, payload.as_eleven)?;
 
+    publish_game_event(&state, game_id, GameEventKind::AceValueChanged, Some(claims.email.clone()));
+
     Ok(Json(response))
 }
 
@@ -821,8 +1089,23 @@ pub async fn finish_game(
     Extension(claims): Extension<Claims>,
     Path(game_id): Path<Uuid>,
 ) -> Result<Json<GameResult>, ApiError> {
+    let game_state = state.game_service.get_game_state(game_id)?;
+    if !game_state.started {
+        return Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "GAME_NOT_STARTED",
+            "Game has not started yet",
+        ));
+    }
+
     let result = state.game_service.finish_game(game_id)?;
 
+    if let Err(err) = state.repository.mark_game_finished(game_id).await {
+        tracing::warn!(game_id = %game_id, error = ?err, "Failed to persist game as finished");
+    }
+
+    publish_game_event(&state, game_id, GameEventKind::GameFinished, Some(claims.email.clone()));
+
     Ok(Json(result))
 }
 
@@ -903,6 +1186,187 @@ pub async fn get_game_results(
     Ok(Json(result))
 }
 
+// ============================================================================
+// Game Lobby Endpoints
+// ============================================================================
+//
+// `create_game` above still accepts a full player roster up front for
+// backward compatibility, but `GameService` now also supports creating a
+// game with a partial (or empty) roster and opening it for matchmaking: a
+// game sits in the waiting state until `start_game` locks the roster and
+// deals initial hands. `draw_card` and `finish_game` reject a game that
+// hasn't started yet with `GAME_NOT_STARTED`.
+
+/// Query parameters for `GET /api/v1/games`.
+#[derive(Debug, Deserialize)]
+pub struct ListGamesQuery {
+    /// 1-indexed page number; defaults to 1.
+    #[serde(default)]
+    pub page: Option<usize>,
+
+    /// Games per page; defaults to 20.
+    #[serde(default)]
+    pub page_size: Option<usize>,
+}
+
+const DEFAULT_GAMES_PAGE_SIZE: usize = 20;
+
+/// A page of lobby listings.
+#[derive(Debug, Serialize)]
+pub struct ListGamesResponse {
+    /// Games on this page, most recently created first.
+    pub games: Vec<blackjack_service::GameSummary>,
+
+    /// Page number this response corresponds to.
+    pub page: usize,
+
+    /// Page size used to produce this response.
+    pub page_size: usize,
+
+    /// Total number of games across all pages.
+    pub total: usize,
+}
+
+/// Lists games available to join or spectate, with their current status and
+/// player count.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/games?page=1&page_size=20`
+///
+/// # Authentication
+///
+/// No authentication required (public endpoint), matching `create_game`.
+#[tracing::instrument(skip(state))]
+pub async fn list_games(
+    State(state): State<crate::AppState>,
+    axum::extract::Query(query): axum::extract::Query<ListGamesQuery>,
+) -> Result<Json<ListGamesResponse>, ApiError> {
+    let page = query.page.unwrap_or(1).max(1);
+    let page_size = query.page_size.unwrap_or(DEFAULT_GAMES_PAGE_SIZE).clamp(1, 100);
+
+    let (games, total) = state.game_service.list_games(page, page_size)?;
+
+    Ok(Json(ListGamesResponse {
+        games,
+        page,
+        page_size,
+        total,
+    }))
+}
+
+/// Joins the authenticated user to a not-yet-started game.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/games/:game_id/join`
+///
+/// # Authentication
+///
+/// **Required** - Must include valid JWT token in Authorization header.
+///
+/// # Errors
+///
+/// - **404 Not Found** - Game does not exist
+/// - **409 Conflict** - Game already started, already full (`max_players`),
+///   or the user is already a player
+#[tracing::instrument(skip(state), fields(player_email = %claims.email))]
+pub async fn join_game(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(game_id): Path<Uuid>,
+) -> Result<Json<GameStateResponse>, ApiError> {
+    let game_state = state.game_service.join_game(game_id, &claims.email)?;
+
+    if let Err(err) = state
+        .repository
+        .upsert_player(crate::persistence::StoredPlayer {
+            game_id,
+            email: claims.email.clone(),
+            points: 0,
+            busted: false,
+            standing: false,
+        })
+        .await
+    {
+        tracing::warn!(game_id = %game_id, email = %claims.email, error = ?err, "Failed to persist joined player row");
+    }
+
+    tracing::info!(game_id = %game_id, email = %claims.email, "Player joined game");
+
+    Ok(Json(game_state))
+}
+
+/// Removes the authenticated user from a not-yet-started game.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/games/:game_id/leave`
+///
+/// # Authentication
+///
+/// **Required** - Must include valid JWT token in Authorization header.
+///
+/// # Errors
+///
+/// - **404 Not Found** - Game does not exist, or user is not a player
+/// - **409 Conflict** - Game already started
+#[tracing::instrument(skip(state), fields(player_email = %claims.email))]
+pub async fn leave_game(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(game_id): Path<Uuid>,
+) -> Result<Json<GameStateResponse>, ApiError> {
+    let game_state = state.game_service.leave_game(game_id, &claims.email)?;
+
+    if let Err(err) = state.repository.remove_player(game_id, &claims.email).await {
+        tracing::warn!(game_id = %game_id, email = %claims.email, error = ?err, "Failed to persist player leaving");
+    }
+
+    tracing::info!(game_id = %game_id, email = %claims.email, "Player left game");
+
+    Ok(Json(game_state))
+}
+
+/// Locks the roster and deals initial hands, transitioning a game out of the
+/// waiting state.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/games/:game_id/start`
+///
+/// # Authentication
+///
+/// **Required** - Must include valid JWT token in Authorization header. Any
+/// current player may start the game once `min_players` is met.
+///
+/// # Errors
+///
+/// - **404 Not Found** - Game does not exist
+/// - **403 Forbidden** - Caller is not a player in this game
+/// - **409 Conflict** - Game already started, or below `min_players`
+#[tracing::instrument(skip(state), fields(player_email = %claims.email))]
+pub async fn start_game(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(game_id): Path<Uuid>,
+) -> Result<Json<GameStateResponse>, ApiError> {
+    let game_state = state.game_service.get_game_state(game_id)?;
+    if !game_state.players.contains_key(&claims.email) {
+        return Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "NOT_IN_GAME",
+            "You are not a player in this game",
+        ));
+    }
+
+    let game_state = state.game_service.start_game(game_id)?;
+
+    tracing::info!(game_id = %game_id, "Game started");
+
+    Ok(Json(game_state))
+}
+
 // ============================================================================
 // M7: User Management Endpoints
 // ============================================================================
@@ -932,9 +1396,9 @@ pub struct RegisterResponse {
 
 /// Registers a new user
 ///
-/// Creates a new user account with email and password.
-/// Password is hashed before storage (currently using placeholder,
-/// will be upgraded to Argon2 in M8).
+/// Creates a new user account with email and password. The password is
+/// hashed with Argon2id (see [`crate::password`]) before it ever reaches
+/// `UserService`, so only the PHC-format hash string is persisted.
 ///
 /// # Endpoint
 ///
@@ -973,8 +1437,20 @@ pub async fn register_user(
     State(state): State<crate::AppState>,
     Json(payload): Json<RegisterRequest>,
 ) -> Result<Json<RegisterResponse>, ApiError> {
-    let user_id = state.user_service.register(payload.email.clone(), payload.password)?;
-    
+    let password_hash = crate::password::hash_password(&payload.password, &state.config.password)
+        .map_err(|err| {
+            tracing::error!(error = ?err, "Failed to hash password during registration");
+            ApiError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "PASSWORD_HASH_FAILED",
+                "Failed to process password",
+            )
+        })?;
+
+    let user_id = state
+        .user_service
+        .register(payload.email.clone(), password_hash)?;
+
     tracing::info!(
         user_id = %user_id,
         email = %payload.email,
@@ -989,84 +1465,416 @@ pub async fn register_user(
 }
 
 // ============================================================================
-// Invitation Management Endpoints
+// Email Verification and Password Reset
 // ============================================================================
 
-/// Request to create a game invitation
-#[derive(Debug, Deserialize)]
-pub struct CreateInvitationRequest {
-    /// Email of the user to invite
-    pub invitee_email: String,
-    
-    /// Optional timeout in seconds (defaults to config value)
-    pub timeout_seconds: Option<u64>,
-}
+use crate::action_token::{ActionTokenError, ActionTokenPurpose};
+use crate::mailer::OutgoingEmail;
 
-/// Response for created invitation
+/// Response acknowledging a verification email was (re)sent
 #[derive(Debug, Serialize)]
-pub struct CreateInvitationResponse {
-    /// Invitation ID
-    pub invitation_id: Uuid,
-    
-    /// Invitee email
-    pub invitee_email: String,
-    
-    /// Expiration timestamp
-    pub expires_at: String,
-    
-    /// Success message
+pub struct VerifyRequestResponse {
     pub message: String,
 }
 
-/// Creates a game invitation
-///
-/// Game creator can invite additional players to join the game.
-/// Invitations have a configurable timeout.
+/// Sends (or re-sends) an email-verification link to the authenticated user
 ///
 /// # Endpoint
 ///
-/// `POST /api/v1/games/:game_id/invitations`
+/// `POST /api/v1/auth/verify/request`
 ///
 /// # Authentication
 ///
-/// **Required** - Must be the game creator.
-///
-/// # Request Body
-///
-/// ```json
-/// {
-///   "invitee_email": "newplayer@example.com",
-///   "timeout_seconds": 600
-/// }
-/// ```
-#[tracing::instrument(skip(state))]
-pub async fn create_invitation(
+/// **Required**.
+#[tracing::instrument(skip(state), fields(email = %claims.email))]
+pub async fn request_email_verification(
     State(state): State<crate::AppState>,
     Extension(claims): Extension<Claims>,
-    Path(game_id): Path<Uuid>,
-    Json(payload): Json<CreateInvitationRequest>,
-) -> Result<Json<CreateInvitationResponse>, ApiError> {
-    // Verify user is game creator
-    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
-        ApiError::new(StatusCode::BAD_REQUEST, "INVALID_USER_ID", "Invalid user ID")
-    })?;
+) -> Result<Json<VerifyRequestResponse>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id)
+        .map_err(|_| ApiError::new(StatusCode::BAD_REQUEST, "INVALID_USER_ID", "Invalid user ID"))?;
 
-    if !state.game_service.is_game_creator(game_id, user_id)? {
-        return Err(ApiError::new(
-            StatusCode::FORBIDDEN,
-            "NOT_CREATOR",
-            "Only game creator can send invitations",
-        ));
-    }
+    let token = state
+        .action_tokens
+        .issue(user_id, ActionTokenPurpose::EmailVerification);
 
-    // Get user email for inviter
-    let user = state.user_service.get_user(user_id)?;
+    let confirm_url = format!(
+        "{}/api/v1/auth/verify/confirm?token={token}",
+        state.config.server.public_base_url
+    );
+
+    let _ = state
+        .mailer
+        .send(OutgoingEmail {
+            to: claims.email.clone(),
+            subject: "Verify your Blackjack account".to_string(),
+            body: format!("Confirm your email by visiting: {confirm_url}"),
+        })
+        .await;
+
+    tracing::info!(email = %claims.email, "Verification email sent");
+
+    Ok(Json(VerifyRequestResponse {
+        message: "Verification email sent".to_string(),
+    }))
+}
+
+/// Query parameters for `GET /api/v1/auth/verify/confirm`
+#[derive(Debug, Deserialize)]
+pub struct VerifyConfirmQuery {
+    pub token: String,
+}
+
+/// Response for a successful verification
+#[derive(Debug, Serialize)]
+pub struct VerifyConfirmResponse {
+    pub verified: bool,
+}
+
+/// Confirms an email-verification token, flipping the user's `verified` flag
+///
+/// # Endpoint
+///
+/// `GET /api/v1/auth/verify/confirm?token=...`
+///
+/// # Authentication
+///
+/// None - the single-use token in the query string is the credential.
+///
+/// # Errors
+///
+/// - **400 Bad Request** - unknown, already-used, or wrong-purpose token
+///   (`INVALID_TOKEN`), or expired token (`TOKEN_EXPIRED`)
+#[tracing::instrument(skip(state, query))]
+pub async fn confirm_email_verification(
+    State(state): State<crate::AppState>,
+    axum::extract::Query(query): axum::extract::Query<VerifyConfirmQuery>,
+) -> Result<Json<VerifyConfirmResponse>, ApiError> {
+    let user_id = state
+        .action_tokens
+        .consume(&query.token, ActionTokenPurpose::EmailVerification)
+        .map_err(map_action_token_error)?;
+
+    state.user_service.mark_email_verified(user_id)?;
+
+    tracing::info!(user_id = %user_id, "Email verified");
+
+    Ok(Json(VerifyConfirmResponse { verified: true }))
+}
+
+/// Request body for `POST /api/v1/auth/password/reset-request`
+#[derive(Debug, Deserialize)]
+pub struct PasswordResetRequestRequest {
+    pub email: String,
+}
+
+/// Generic acknowledgement, identical whether or not the email exists
+#[derive(Debug, Serialize)]
+pub struct PasswordResetRequestResponse {
+    pub message: String,
+}
+
+/// Starts a password-reset flow for the given email
+///
+/// # Endpoint
+///
+/// `POST /api/v1/auth/password/reset-request`
+///
+/// # Authentication
+///
+/// None (public - this *is* the account-recovery entry point).
+///
+/// # Security
+///
+/// Always responds identically whether or not `email` belongs to an
+/// account, to avoid account enumeration. The reset email itself (if any)
+/// is only ever sent to the address on file.
+#[tracing::instrument(skip(state))]
+pub async fn request_password_reset(
+    State(state): State<crate::AppState>,
+    Json(payload): Json<PasswordResetRequestRequest>,
+) -> Result<Json<PasswordResetRequestResponse>, ApiError> {
+    if let Ok(user) = state.user_service.get_user_by_email(&payload.email) {
+        let token = state
+            .action_tokens
+            .issue(user.id, ActionTokenPurpose::PasswordReset);
+
+        let reset_url = format!(
+            "{}/reset-password?token={token}",
+            state.config.server.public_base_url
+        );
+
+        let _ = state
+            .mailer
+            .send(OutgoingEmail {
+                to: user.email.clone(),
+                subject: "Reset your Blackjack password".to_string(),
+                body: format!("Reset your password by visiting: {reset_url}"),
+            })
+            .await;
+    }
+
+    // Same response regardless of whether `email` matched a user.
+    Ok(Json(PasswordResetRequestResponse {
+        message: "If an account with that email exists, a reset link has been sent".to_string(),
+    }))
+}
+
+/// Request body for `POST /api/v1/auth/password/reset-confirm`
+#[derive(Debug, Deserialize)]
+pub struct PasswordResetConfirmRequest {
+    pub token: String,
+    pub new_password: String,
+}
+
+/// Response for a successful password reset
+#[derive(Debug, Serialize)]
+pub struct PasswordResetConfirmResponse {
+    pub message: String,
+}
+
+/// Completes a password reset, consuming the token and setting a new
+/// Argon2id hash
+///
+/// # Endpoint
+///
+/// `POST /api/v1/auth/password/reset-confirm`
+///
+/// # Errors
+///
+/// - **400 Bad Request** - unknown, already-used, or wrong-purpose token
+///   (`INVALID_TOKEN`), or expired token (`TOKEN_EXPIRED`)
+#[tracing::instrument(skip(state, payload))]
+pub async fn confirm_password_reset(
+    State(state): State<crate::AppState>,
+    Json(payload): Json<PasswordResetConfirmRequest>,
+) -> Result<Json<PasswordResetConfirmResponse>, ApiError> {
+    let user_id = state
+        .action_tokens
+        .consume(&payload.token, ActionTokenPurpose::PasswordReset)
+        .map_err(map_action_token_error)?;
+
+    let password_hash =
+        crate::password::hash_password(&payload.new_password, &state.config.password).map_err(
+            |err| {
+                tracing::error!(error = ?err, "Failed to hash password during reset");
+                ApiError::new(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "PASSWORD_HASH_FAILED",
+                    "Failed to process password",
+                )
+            },
+        )?;
+
+    state.user_service.set_password_hash(user_id, password_hash)?;
+
+    tracing::info!(user_id = %user_id, "Password reset completed");
+
+    Ok(Json(PasswordResetConfirmResponse {
+        message: "Password has been reset".to_string(),
+    }))
+}
+
+fn map_action_token_error(err: ActionTokenError) -> ApiError {
+    let (code, message) = match err {
+        ActionTokenError::Invalid => (
+            "INVALID_TOKEN",
+            "Token is invalid, already used, or was issued for a different purpose",
+        ),
+        ActionTokenError::Expired => ("TOKEN_EXPIRED", "Token has expired"),
+    };
+    ApiError::new(StatusCode::BAD_REQUEST, code, message)
+}
+
+// ============================================================================
+// Invitation Management Endpoints
+// ============================================================================
+
+/// Request to create a game invitation
+#[derive(Debug, Deserialize)]
+pub struct CreateInvitationRequest {
+    /// Email of the user to invite
+    pub invitee_email: String,
+
+    /// Human-readable timeout, e.g. `"10m"`, `"1h30m"`, `"90s"`. Takes
+    /// precedence over `timeout_seconds` when both are supplied.
+    pub timeout: Option<String>,
+
+    /// Optional timeout in seconds (defaults to config value). Ignored if
+    /// `timeout` is also present.
+    pub timeout_seconds: Option<u64>,
+}
+
+/// Parses a compact duration string (`"10m"`, `"1h30m"`, `"90s"`, `"2h"`)
+/// into a total number of seconds.
+///
+/// Scans the string as a sequence of `<number><unit>` pairs (`s`/`m`/`h`/`d`)
+/// and sums them. Returns `None` if the string is empty, malformed, or sums
+/// to zero - callers turn that into `400 INVALID_TIMEOUT`.
+fn parse_timeout_duration(input: &str) -> Option<u64> {
+    let mut chars = input.trim().char_indices().peekable();
+    let mut total: u64 = 0;
+    let mut saw_pair = false;
+
+    while let Some(&(start, c)) = chars.peek() {
+        if !c.is_ascii_digit() {
+            return None;
+        }
+
+        let mut end = start;
+        while let Some(&(idx, c)) = chars.peek() {
+            if !c.is_ascii_digit() {
+                break;
+            }
+            end = idx + c.len_utf8();
+            chars.next();
+        }
+        let amount: u64 = input[start..end].parse().ok()?;
+
+        let (_, unit) = chars.next()?;
+        let multiplier: u64 = match unit {
+            's' => 1,
+            'm' => 60,
+            'h' => 3_600,
+            'd' => 86_400,
+            _ => return None,
+        };
+
+        total = total.checked_add(amount.checked_mul(multiplier)?)?;
+        saw_pair = true;
+    }
+
+    (saw_pair && total > 0).then_some(total)
+}
+
+/// Resolves the effective invitation timeout from the two ways a caller can
+/// express it, preferring the human-readable `timeout` string over raw
+/// `timeout_seconds` when both are supplied, and clamps the result to the
+/// configured maximum.
+fn resolve_invitation_timeout(
+    state: &crate::AppState,
+    timeout: Option<&str>,
+    timeout_seconds: Option<u64>,
+) -> Result<Option<u64>, ApiError> {
+    let seconds = match timeout {
+        Some(raw) => Some(parse_timeout_duration(raw).ok_or_else(|| {
+            ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "INVALID_TIMEOUT",
+                "timeout must be a non-empty duration like \"10m\" or \"1h30m\"",
+            )
+        })?),
+        None => timeout_seconds,
+    };
+
+    Ok(seconds.map(|secs| secs.min(state.config.invitations.max_timeout_seconds)))
+}
+
+#[cfg(test)]
+mod timeout_duration_tests {
+    use super::parse_timeout_duration;
+
+    #[test]
+    fn parses_a_single_unit() {
+        assert_eq!(parse_timeout_duration("10m"), Some(600));
+        assert_eq!(parse_timeout_duration("45s"), Some(45));
+        assert_eq!(parse_timeout_duration("2h"), Some(7_200));
+        assert_eq!(parse_timeout_duration("1d"), Some(86_400));
+    }
+
+    #[test]
+    fn sums_multiple_units_in_order() {
+        assert_eq!(parse_timeout_duration("1h30m"), Some(5_400));
+        assert_eq!(parse_timeout_duration("1d2h3m4s"), Some(93_784));
+    }
+
+    #[test]
+    fn rejects_empty_or_zero() {
+        assert_eq!(parse_timeout_duration(""), None);
+        assert_eq!(parse_timeout_duration("0s"), None);
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!(parse_timeout_duration("m10"), None);
+        assert_eq!(parse_timeout_duration("10x"), None);
+        assert_eq!(parse_timeout_duration("abc"), None);
+        assert_eq!(parse_timeout_duration("10"), None);
+    }
+
+    #[test]
+    fn rejects_overflow_instead_of_wrapping() {
+        assert_eq!(parse_timeout_duration("99999999999999999999d"), None);
+    }
+}
+
+/// Response for created invitation
+#[derive(Debug, Serialize)]
+pub struct CreateInvitationResponse {
+    /// Invitation ID
+    pub invitation_id: Uuid,
+    
+    /// Invitee email
+    pub invitee_email: String,
     
+    /// Expiration timestamp
+    pub expires_at: String,
+    
+    /// Success message
+    pub message: String,
+}
+
+/// Creates a game invitation
+///
+/// Game creator can invite additional players to join the game.
+/// Invitations have a configurable timeout.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/games/:game_id/invitations`
+///
+/// # Authentication
+///
+/// **Required** - Must be the game creator.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "invitee_email": "newplayer@example.com",
+///   "timeout_seconds": 600
+/// }
+/// ```
+#[tracing::instrument(skip(state))]
+pub async fn create_invitation(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(game_id): Path<Uuid>,
+    Json(payload): Json<CreateInvitationRequest>,
+) -> Result<Json<CreateInvitationResponse>, ApiError> {
+    // Verify user is game creator
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(StatusCode::BAD_REQUEST, "INVALID_USER_ID", "Invalid user ID")
+    })?;
+
+    if !state.game_service.is_game_creator(game_id, user_id)? {
+        return Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "NOT_CREATOR",
+            "Only game creator can send invitations",
+        ));
+    }
+
+    // Get user email for inviter
+    let user = state.user_service.get_user(user_id)?;
+    let timeout_seconds =
+        resolve_invitation_timeout(&state, payload.timeout.as_deref(), payload.timeout_seconds)?;
+
     let invitation_id = state.invitation_service.create(
         game_id,
         user.email.clone(),
         payload.invitee_email.clone(),
-        payload.timeout_seconds,
+        timeout_seconds,
     )?;
     
     let invitation = state.invitation_service.get_invitation(invitation_id)?;
@@ -1078,6 +1886,20 @@ pub async fn create_invitation(
         "Invitation created"
     );
 
+    events::publish(
+        &state,
+        NotificationEvent {
+            kind: NotificationKind::InvitationReceived,
+            target_email: Some(invitation.invitee_email.clone()),
+            game_id: Some(game_id),
+            payload: serde_json::json!({
+                "invitation_id": invitation.id,
+                "inviter_email": invitation.inviter_email,
+                "expires_at": invitation.expires_at,
+            }),
+        },
+    );
+
     Ok(Json(CreateInvitationResponse {
         invitation_id: invitation.id,
         invitee_email: invitation.invitee_email.clone(),
@@ -1086,6 +1908,152 @@ pub async fn create_invitation(
     }))
 }
 
+/// Request to create invitations for multiple invitees in one call.
+#[derive(Debug, Deserialize)]
+pub struct BulkCreateInvitationRequest {
+    /// Emails of the users to invite
+    pub invitee_emails: Vec<String>,
+
+    /// Human-readable timeout (see `CreateInvitationRequest::timeout`),
+    /// applied to every invitee in the batch. Takes precedence over
+    /// `timeout_seconds` when both are supplied.
+    pub timeout: Option<String>,
+
+    /// Optional timeout in seconds (defaults to config value), applied to
+    /// every invitee in the batch. Ignored if `timeout` is also present.
+    pub timeout_seconds: Option<u64>,
+}
+
+/// Outcome of a single invitee within a bulk invitation request.
+#[derive(Debug, Serialize)]
+pub struct BulkInvitationResult {
+    /// The invitee this result is for
+    pub invitee_email: String,
+
+    /// The created invitation's ID, if it succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitation_id: Option<Uuid>,
+
+    /// Why this invitee's invitation failed, if it did
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Response for a bulk invitation request.
+#[derive(Debug, Serialize)]
+pub struct BulkCreateInvitationResponse {
+    /// One result per requested invitee, in the same order
+    pub results: Vec<BulkInvitationResult>,
+}
+
+/// Creates invitations for multiple invitees at once.
+///
+/// Runs every `invitation_service.create` call concurrently via
+/// `futures::future::join_all` and reports each invitee's outcome
+/// independently, so one bad or duplicate address doesn't abort the rest
+/// of the batch.
+///
+/// # Endpoint
+///
+/// `POST /api/v1/games/:game_id/invitations/bulk`
+///
+/// # Authentication
+///
+/// **Required** - Must be the game creator. Checked once up front rather
+/// than per invitee.
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "invitee_emails": ["p1@example.com", "p2@example.com"],
+///   "timeout_seconds": 600
+/// }
+/// ```
+#[tracing::instrument(skip(state))]
+pub async fn create_invitations_bulk(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(game_id): Path<Uuid>,
+    Json(payload): Json<BulkCreateInvitationRequest>,
+) -> Result<Json<BulkCreateInvitationResponse>, ApiError> {
+    // Verify user is game creator - checked once for the whole batch
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(StatusCode::BAD_REQUEST, "INVALID_USER_ID", "Invalid user ID")
+    })?;
+
+    if !state.game_service.is_game_creator(game_id, user_id)? {
+        return Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "NOT_CREATOR",
+            "Only game creator can send invitations",
+        ));
+    }
+
+    // Get user email for inviter - looked up once, not per invitee
+    let user = state.user_service.get_user(user_id)?;
+    let timeout_seconds =
+        resolve_invitation_timeout(&state, payload.timeout.as_deref(), payload.timeout_seconds)?;
+
+    let outcomes = futures::future::join_all(payload.invitee_emails.into_iter().map(
+        |invitee_email| {
+            let state = &state;
+            let inviter_email = user.email.clone();
+            async move {
+                let result = state.invitation_service.create(
+                    game_id,
+                    inviter_email,
+                    invitee_email.clone(),
+                    timeout_seconds,
+                );
+                (invitee_email, result)
+            }
+        },
+    ))
+    .await;
+
+    let results: Vec<BulkInvitationResult> = outcomes
+        .into_iter()
+        .map(|(invitee_email, result)| match result {
+            Ok(invitation_id) => {
+                if let Ok(invitation) = state.invitation_service.get_invitation(invitation_id) {
+                    events::publish(
+                        &state,
+                        NotificationEvent {
+                            kind: NotificationKind::InvitationReceived,
+                            target_email: Some(invitation.invitee_email.clone()),
+                            game_id: Some(game_id),
+                            payload: serde_json::json!({
+                                "invitation_id": invitation.id,
+                                "inviter_email": invitation.inviter_email,
+                                "expires_at": invitation.expires_at,
+                            }),
+                        },
+                    );
+                }
+                BulkInvitationResult {
+                    invitee_email,
+                    invitation_id: Some(invitation_id),
+                    error: None,
+                }
+            }
+            Err(err) => BulkInvitationResult {
+                invitee_email,
+                invitation_id: None,
+                error: Some(err.to_string()),
+            },
+        })
+        .collect();
+
+    tracing::info!(
+        game_id = %game_id,
+        count = results.len(),
+        "Bulk invitations processed"
+    );
+
+    Ok(Json(BulkCreateInvitationResponse { results }))
+}
+
 /// Response for pending invitations list
 #[derive(Debug, Serialize)]
 pub struct PendingInvitationsResponse {
@@ -1093,20 +2061,67 @@ pub struct PendingInvitationsResponse {
     pub invitations: Vec<InvitationInfo>,
 }
 
+/// Lifecycle state of a game invitation.
+///
+/// `invitation_service` tracks an invitation through its full lifecycle
+/// rather than just pending-or-not, so a caller can tell a declined
+/// invitation from one that simply timed out, or from one its creator
+/// revoked before the invitee answered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvitationStatus {
+    Pending,
+    Accepted,
+    Declined,
+    Expired,
+    Revoked,
+}
+
 /// Information about a single invitation
 #[derive(Debug, Serialize)]
 pub struct InvitationInfo {
     /// Invitation ID
     pub id: Uuid,
-    
+
     /// Game ID
     pub game_id: Uuid,
-    
-    /// Inviter user ID
+
+    /// Inviter user ID, resolved from `inviter_email` via `UserService`
     pub inviter_id: Uuid,
-    
+
+    /// Inviter's email address
+    pub inviter_email: String,
+
     /// Expiration timestamp
     pub expires_at: String,
+
+    /// Current lifecycle state of the invitation
+    pub status: InvitationStatus,
+}
+
+/// Resolves each distinct email in `emails` to its `User::id` in a single
+/// batched lookup, rather than one `get_user_by_email` call per invitation.
+///
+/// An email with no matching user (deleted account, data drift) is simply
+/// absent from the returned map; callers fall back to `Uuid::nil()` rather
+/// than failing the whole listing.
+fn resolve_inviter_ids(
+    state: &crate::AppState,
+    emails: impl IntoIterator<Item = String>,
+) -> HashMap<String, Uuid> {
+    let distinct_emails: Vec<String> = emails
+        .into_iter()
+        .collect::<std::collections::HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    match state.user_service.get_users_by_emails(&distinct_emails) {
+        Ok(users) => users.into_iter().map(|user| (user.email, user.id)).collect(),
+        Err(err) => {
+            tracing::warn!(error = ?err, "Failed to resolve inviter emails to user IDs");
+            HashMap::new()
+        }
+    }
 }
 
 /// Gets pending invitations for authenticated user
@@ -1126,18 +2141,24 @@ pub async fn get_pending_invitations(
     Extension(claims): Extension<Claims>,
 ) -> Result<Json<PendingInvitationsResponse>, ApiError> {
     let invitations = state.invitation_service.get_pending_for_user(&claims.email);
-    
+
+    let inviter_ids = resolve_inviter_ids(
+        &state,
+        invitations.iter().map(|inv| inv.inviter_email.clone()),
+    );
+
     // Service j√° retorna Vec<InvitationInfo>, mas precisamos converter para nosso tipo local
     let invitation_infos: Vec<InvitationInfo> = invitations
         .into_iter()
         .map(|inv| {
-            // Parse inviter_email to get inviter_id (simplified for now)
-            let inviter_id = Uuid::new_v4(); // TODO: lookup real user_id
+            let inviter_id = inviter_ids.get(&inv.inviter_email).copied().unwrap_or_default();
             InvitationInfo {
                 id: inv.id,
                 game_id: inv.game_id,
                 inviter_id,
+                inviter_email: inv.inviter_email,
                 expires_at: inv.expires_at,
+                status: InvitationStatus::Pending,
             }
         })
         .collect();
@@ -1147,6 +2168,74 @@ pub async fn get_pending_invitations(
     }))
 }
 
+/// Query parameters for `GET /api/v1/invitations`.
+#[derive(Debug, Deserialize)]
+pub struct ListInvitationsQuery {
+    /// Restrict to invitations in this state.
+    pub status: Option<InvitationStatus>,
+
+    /// Restrict to invitations for this game.
+    pub game_id: Option<Uuid>,
+}
+
+/// Response for a filtered invitation listing.
+#[derive(Debug, Serialize)]
+pub struct ListInvitationsResponse {
+    pub invitations: Vec<InvitationInfo>,
+}
+
+/// Lists the authenticated user's own invitations (as inviter or invitee),
+/// optionally filtered by `status` and/or `game_id`.
+///
+/// Unlike `get_pending_invitations`, this includes invitations in any
+/// lifecycle state.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/invitations?status=pending&game_id=...`
+///
+/// # Authentication
+///
+/// **Required** - User must be authenticated.
+#[tracing::instrument(skip(state))]
+pub async fn list_invitations(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    axum::extract::Query(query): axum::extract::Query<ListInvitationsQuery>,
+) -> Result<Json<ListInvitationsResponse>, ApiError> {
+    let invitations: Vec<_> = state
+        .invitation_service
+        .list_for_user(&claims.email)
+        .into_iter()
+        .filter(|inv| query.status.map_or(true, |status| inv.status == status))
+        .filter(|inv| query.game_id.map_or(true, |game_id| inv.game_id == game_id))
+        .collect();
+
+    let inviter_ids = resolve_inviter_ids(
+        &state,
+        invitations.iter().map(|inv| inv.inviter_email.clone()),
+    );
+
+    let invitation_infos: Vec<InvitationInfo> = invitations
+        .into_iter()
+        .map(|inv| {
+            let inviter_id = inviter_ids.get(&inv.inviter_email).copied().unwrap_or_default();
+            InvitationInfo {
+                id: inv.id,
+                game_id: inv.game_id,
+                inviter_id,
+                inviter_email: inv.inviter_email,
+                expires_at: inv.expires_at,
+                status: inv.status,
+            }
+        })
+        .collect();
+
+    Ok(Json(ListInvitationsResponse {
+        invitations: invitation_infos,
+    }))
+}
+
 /// Response for invitation acceptance
 #[derive(Debug, Serialize)]
 pub struct AcceptInvitationResponse {
@@ -1199,6 +2288,19 @@ pub async fn accept_invitation(
         "Invitation accepted, player added to game"
     );
 
+    events::publish(
+        &state,
+        NotificationEvent {
+            kind: NotificationKind::InvitationAccepted,
+            target_email: Some(invitation.inviter_email.clone()),
+            game_id: Some(invitation.game_id),
+            payload: serde_json::json!({
+                "invitation_id": invitation_id,
+                "invitee_email": claims.email,
+            }),
+        },
+    );
+
     Ok(Json(AcceptInvitationResponse {
         game_id: invitation.game_id,
         message: "Invitation accepted, joined game successfully".to_string(),
@@ -1247,11 +2349,102 @@ pub async fn decline_invitation(
         "Invitation declined"
     );
 
+    events::publish(
+        &state,
+        NotificationEvent {
+            kind: NotificationKind::InvitationDeclined,
+            target_email: Some(invitation.inviter_email.clone()),
+            game_id: Some(invitation.game_id),
+            payload: serde_json::json!({
+                "invitation_id": invitation_id,
+                "invitee_email": claims.email,
+            }),
+        },
+    );
+
     Ok(Json(DeclineInvitationResponse {
         message: "Invitation declined".to_string(),
     }))
 }
 
+/// Response for invitation revocation
+#[derive(Debug, Serialize)]
+pub struct RevokeInvitationResponse {
+    /// Success message
+    pub message: String,
+}
+
+/// Revokes a still-pending invitation.
+///
+/// Transitions the invitation to [`InvitationStatus::Revoked`] so it stops
+/// appearing to the invitee as something they can still accept.
+///
+/// # Endpoint
+///
+/// `DELETE /api/v1/games/:game_id/invitations/:id`
+///
+/// # Authentication
+///
+/// **Required** - Must be the game creator (same check as `create_invitation`).
+///
+/// # Errors
+///
+/// - **403 Forbidden** - Caller is not the game creator
+/// - **404 Not Found** - Invitation does not exist or isn't for this game
+/// - **409 Conflict** - Invitation is no longer pending
+#[tracing::instrument(skip(state))]
+pub async fn revoke_invitation(
+    State(state): State<crate::AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((game_id, invitation_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<RevokeInvitationResponse>, ApiError> {
+    let user_id = Uuid::parse_str(&claims.user_id).map_err(|_| {
+        ApiError::new(StatusCode::BAD_REQUEST, "INVALID_USER_ID", "Invalid user ID")
+    })?;
+
+    if !state.game_service.is_game_creator(game_id, user_id)? {
+        return Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "NOT_CREATOR",
+            "Only game creator can revoke invitations",
+        ));
+    }
+
+    let invitation = state.invitation_service.get_invitation(invitation_id)?;
+    if invitation.game_id != game_id {
+        return Err(ApiError::new(
+            StatusCode::NOT_FOUND,
+            "INVITATION_NOT_FOUND",
+            "Invitation does not belong to this game",
+        ));
+    }
+    if invitation.status != InvitationStatus::Pending {
+        return Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "INVITATION_NOT_PENDING",
+            "Only a pending invitation can be revoked",
+        ));
+    }
+
+    state.invitation_service.revoke(invitation_id)?;
+
+    tracing::info!(invitation_id = %invitation_id, game_id = %game_id, "Invitation revoked");
+
+    events::publish(
+        &state,
+        NotificationEvent {
+            kind: NotificationKind::InvitationRevoked,
+            target_email: Some(invitation.invitee_email.clone()),
+            game_id: Some(game_id),
+            payload: serde_json::json!({ "invitation_id": invitation_id }),
+        },
+    );
+
+    Ok(Json(RevokeInvitationResponse {
+        message: "Invitation revoked".to_string(),
+    }))
+}
+
 // ============================================================================
 // M7: Turn-Based Gameplay Endpoints
 // ============================================================================
@@ -1291,7 +2484,18 @@ pub async fn stand(
     Path(game_id): Path<Uuid>,
 ) -> Result<Json<StandResponse>, ApiError> {
     let game_state = state.game_service.stand(game_id, &claims.email)?;
-    
+
+    publish_game_event(
+        &state,
+        game_id,
+        if game_state.finished {
+            GameEventKind::GameFinished
+        } else {
+            GameEventKind::TurnChanged
+        },
+        Some(claims.email.clone()),
+    );
+
     // Get player info from response
     let player_info = game_state.players.get(&claims.email)
         .ok_or_else(|| ApiError::new(
@@ -1299,7 +2503,26 @@ pub async fn stand(
             "PLAYER_NOT_FOUND",
             "Player not found in game",
         ))?;
-    
+
+    if let Err(err) = state
+        .repository
+        .upsert_player(crate::persistence::StoredPlayer {
+            game_id,
+            email: claims.email.clone(),
+            points: player_info.points as i64,
+            busted: player_info.busted,
+            standing: true,
+        })
+        .await
+    {
+        tracing::warn!(game_id = %game_id, email = %claims.email, error = ?err, "Failed to persist player row after standing");
+    }
+    if game_state.finished {
+        if let Err(err) = state.repository.mark_game_finished(game_id).await {
+            tracing::warn!(game_id = %game_id, error = ?err, "Failed to persist game as finished");
+        }
+    }
+
     tracing::info!(
         game_id = %game_id,
         email = %claims.email,
@@ -1308,6 +2531,29 @@ pub async fn stand(
         "Player stood"
     );
 
+    events::publish(
+        &state,
+        NotificationEvent {
+            kind: NotificationKind::PlayerStood,
+            target_email: None,
+            game_id: Some(game_id),
+            payload: serde_json::json!({ "email": claims.email }),
+        },
+    );
+    events::publish(
+        &state,
+        NotificationEvent {
+            kind: if game_state.finished {
+                NotificationKind::GameFinished
+            } else {
+                NotificationKind::TurnAdvanced
+            },
+            target_email: None,
+            game_id: Some(game_id),
+            payload: serde_json::json!({ "current_turn_player": game_state.current_turn_player }),
+        },
+    );
+
     Ok(Json(StandResponse {
         points: player_info.points as u32,
         busted: player_info.busted,
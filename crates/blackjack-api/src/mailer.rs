@@ -0,0 +1,103 @@
+//! Mailer abstraction for transactional email
+//!
+//! Backs the email-verification and password-reset flows. Production sends
+//! over SMTP; tests and local development capture messages in memory
+//! instead of needing a real mail server.
+
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::sync::Mutex;
+
+/// A transactional email to deliver.
+#[derive(Debug, Clone)]
+pub struct OutgoingEmail {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Something that can deliver an [`OutgoingEmail`].
+///
+/// `AppState` holds an `Arc<dyn Mailer>` so the SMTP implementation can be
+/// swapped for the in-memory capture one in tests without touching handler
+/// code.
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, email: OutgoingEmail) -> Result<(), MailerError>;
+}
+
+/// Failure sending a message.
+#[derive(Debug, Clone)]
+pub struct MailerError(pub String);
+
+/// SMTP-backed mailer for production use.
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(smtp_url: &str, from: &str) -> Result<Self, MailerError> {
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::from_url(smtp_url)
+            .map_err(|err| MailerError(err.to_string()))?
+            .build();
+        let from = from.parse().map_err(|err: lettre::address::AddressError| {
+            MailerError(err.to_string())
+        })?;
+        Ok(SmtpMailer { transport, from })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, email: OutgoingEmail) -> Result<(), MailerError> {
+        let to: Mailbox = email
+            .to
+            .parse()
+            .map_err(|err: lettre::address::AddressError| MailerError(err.to_string()))?;
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(email.subject)
+            .body(email.body)
+            .map_err(|err| MailerError(err.to_string()))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|err| MailerError(err.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// In-memory mailer that captures sent messages instead of delivering them.
+///
+/// Used in tests (and local development without an SMTP server) to assert
+/// what would have been sent - e.g. extracting the verification token out
+/// of a captured email body - without a real mail server.
+#[derive(Default)]
+pub struct CapturingMailer {
+    sent: Mutex<Vec<OutgoingEmail>>,
+}
+
+impl CapturingMailer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All messages sent so far, in send order.
+    pub fn sent_emails(&self) -> Vec<OutgoingEmail> {
+        self.sent.lock().expect("capturing mailer poisoned").clone()
+    }
+}
+
+#[async_trait]
+impl Mailer for CapturingMailer {
+    async fn send(&self, email: OutgoingEmail) -> Result<(), MailerError> {
+        self.sent.lock().expect("capturing mailer poisoned").push(email);
+        Ok(())
+    }
+}
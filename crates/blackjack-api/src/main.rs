@@ -25,13 +25,33 @@
 //! # Production with custom port
 //! BLACKJACK_SERVER_PORT=3000 cargo run -p blackjack-api --release
 //! ```
+//!
+//! # TLS
+//!
+//! Plain HTTP by default. Add a `[tls]` section to `config.toml` (domains,
+//! contact email, certificate cache directory) to have the server provision
+//! and auto-renew its own Let's Encrypt certificate via ACME TLS-ALPN-01 and
+//! serve HTTPS directly - see `blackjack_api::tls` - which removes the need
+//! for a reverse proxy in front of small self-hosted deployments.
 
 use blackjack_api::config::AppConfig;
-use blackjack_api::handlers::login;
+use blackjack_api::action_token::ActionTokenStore;
+use blackjack_api::events::{events_stream, NotificationEvent, NOTIFICATION_CHANNEL_CAPACITY};
+use blackjack_api::handlers::{
+    confirm_email_verification, confirm_password_reset, create_invitations_bulk, join_game,
+    leave_game, list_games, list_invitations, login, logout, refresh,
+    request_email_verification, request_password_reset, revoke_invitation, start_game,
+};
+use blackjack_api::mailer::{CapturingMailer, Mailer, SmtpMailer};
+use blackjack_api::oauth::{authorize, callback, OAuthStateStore};
+use blackjack_api::persistence::{self, InMemoryRepository, Repository, SqliteRepository};
 use blackjack_api::middleware::version_deprecation_middleware;
 use blackjack_api::rate_limiter::RateLimiter;
+use blackjack_api::refresh_token::RefreshTokenStore;
+use blackjack_api::revocation::RevocationList;
+use blackjack_api::websocket::{game_ws_handler, GameBroadcastRegistry};
 use blackjack_api::AppState;
-use axum::routing::post;
+use axum::routing::{delete, get, post};
 use axum::Router;
 use blackjack_service::{GameService, ServiceConfig};
 use std::sync::Arc;
@@ -45,12 +65,43 @@ async fn main() {
 
     // Initialize structured logging with tracing
     // Reads RUST_LOG environment variable for filter configuration
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
-        )
-        .init();
+    //
+    // Composed as a layered subscriber (rather than `fmt().init()` directly)
+    // so the optional tokio-console layer below can be registered
+    // alongside it when enabled.
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    let fmt_layer = tracing_subscriber::fmt::layer();
+    let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+
+    let subscriber = tracing_subscriber::registry().with(env_filter).with(fmt_layer);
+
+    // Optional tokio-console instrumentation for debugging stalls in the
+    // async game loop and WebSocket/broadcast machinery. Requires both the
+    // `tokio_console` feature (it needs `tokio_unstable` + instrumented
+    // tokio) and the `BLACKJACK_CONSOLE=1` env toggle, so it's never
+    // accidentally enabled in a normal run.
+    #[cfg(feature = "tokio_console")]
+    {
+        if std::env::var("BLACKJACK_CONSOLE").as_deref() == Ok("1") {
+            let console_port: u16 = std::env::var("BLACKJACK_CONSOLE_PORT")
+                .ok()
+                .and_then(|port| port.parse().ok())
+                .unwrap_or(6669);
+
+            let console_layer = console_subscriber::ConsoleLayer::builder()
+                .server_addr(([127, 0, 0, 1], console_port))
+                .spawn();
+
+            subscriber.with(console_layer).init();
+        } else {
+            subscriber.init();
+        }
+    }
+    #[cfg(not(feature = "tokio_console"))]
+    subscriber.init();
 
     tracing::info!("Starting Blackjack API server");
 
@@ -74,12 +125,74 @@ async fn main() {
     // Uses sliding window algorithm to track requests per player
     let rate_limiter = RateLimiter::new(app_config.rate_limit.requests_per_minute);
 
+    // Per-game WebSocket broadcast channels, fed by handlers after every
+    // state-changing action. No-op (never subscribed to) when
+    // `websocket.enabled` is false, so this costs nothing on deployments
+    // that keep the pure-HTTP surface.
+    let game_broadcast = Arc::new(GameBroadcastRegistry::new());
+
+    // Blocklist of revoked token `jti`s, consulted by the auth middleware so
+    // a logged-out or leaked token stops working before its `exp` passes.
+    let revoked_tokens = Arc::new(RevocationList::new());
+
+    // Opaque refresh tokens backing short-lived access tokens; see
+    // `blackjack_api::refresh_token`.
+    let refresh_tokens = Arc::new(RefreshTokenStore::new());
+
+    // Outstanding OAuth2 `state` nonces, consulted by the callback handler
+    // to reject requests that aren't a reply to an `authorize` we issued.
+    let oauth_state = Arc::new(OAuthStateStore::new());
+
+    // Durable storage backend. SQLite by default; `persistence.backend =
+    // "memory"` keeps the in-memory store for fast test runs.
+    let repository: Arc<dyn Repository> = match app_config.persistence.backend.as_str() {
+        "memory" => Arc::new(InMemoryRepository::new()),
+        _ => {
+            let pool = persistence::connect(&app_config.persistence.database_url).await;
+            Arc::new(SqliteRepository::new(pool))
+        }
+    };
+
+    // Single-use tokens backing email verification and password reset.
+    let action_tokens = Arc::new(ActionTokenStore::new(app_config.jwt.secret.clone()));
+
+    // SMTP in production, in-memory capture when no `[smtp]` config is
+    // present (e.g. local development and tests).
+    let mailer: Arc<dyn Mailer> = match &app_config.smtp {
+        Some(smtp_config) => Arc::new(
+            SmtpMailer::new(&smtp_config.url, &smtp_config.from_address)
+                .expect("Failed to configure SMTP mailer"),
+        ),
+        None => Arc::new(CapturingMailer::new()),
+    };
+
+    // App-wide bus for invitation and gameplay notifications, fed by
+    // handlers and consumed by the `/api/v1/events` SSE stream. Distinct
+    // from `game_broadcast`: that one pushes one game's full state to its
+    // own watchers, this one pushes small per-user/per-game notifications
+    // to whichever subscriber is listening.
+    let (notifications, _) = tokio::sync::broadcast::channel::<NotificationEvent>(NOTIFICATION_CHANNEL_CAPACITY);
+
+    // Root key for the macaroon capability-token chain (see
+    // `blackjack_api::auth::macaroon`). Reuses the JWT signing secret rather
+    // than introducing a second secret to provision and rotate.
+    let macaroon_root_key = app_config.jwt.secret.clone().into_bytes();
+
     // Build shared application state
     // This state is cloned for each request and provides access to services
     let state = AppState {
         game_service,
         config: app_config.clone(),
         rate_limiter,
+        game_broadcast,
+        revoked_tokens,
+        refresh_tokens,
+        oauth_state,
+        action_tokens,
+        mailer,
+        macaroon_root_key,
+        repository,
+        notifications,
     };
 
     // Configure CORS (Cross-Origin Resource Sharing)
@@ -90,6 +203,68 @@ async fn main() {
     let app = Router::new()
         // Public routes (no authentication required)
         .route("/api/v1/auth/login", post(login))
+        // Protected like any other authenticated endpoint; the auth
+        // middleware attaches the `Claims` extension this handler reads.
+        .route("/api/v1/auth/logout", post(logout))
+        .route("/api/v1/auth/refresh", post(refresh))
+        .route("/api/v1/auth/oauth/:provider/authorize", get(authorize))
+        .route("/api/v1/auth/oauth/:provider/callback", get(callback))
+        .route(
+            "/api/v1/auth/verify/request",
+            post(request_email_verification),
+        )
+        .route(
+            "/api/v1/auth/verify/confirm",
+            get(confirm_email_verification),
+        )
+        .route(
+            "/api/v1/auth/password/reset-request",
+            post(request_password_reset),
+        )
+        .route(
+            "/api/v1/auth/password/reset-confirm",
+            post(confirm_password_reset),
+        )
+        // Push stream of invitation and gameplay notifications; see
+        // `blackjack_api::events`.
+        .route("/api/v1/events", get(events_stream))
+        .route("/api/v1/invitations", get(list_invitations))
+        .route(
+            "/api/v1/games/:game_id/invitations/bulk",
+            post(create_invitations_bulk),
+        )
+        .route(
+            "/api/v1/games/:game_id/invitations/:id",
+            delete(revoke_invitation),
+        )
+        // Game lobby: discover open games and join/leave/start the roster
+        // before the deal, instead of fixing players at creation time.
+        .route("/api/v1/games", get(list_games))
+        .route("/api/v1/games/:game_id/join", post(join_game))
+        .route("/api/v1/games/:game_id/leave", post(leave_game))
+        .route("/api/v1/games/:game_id/start", post(start_game))
+        // Real-time push: clients subscribe instead of polling game state.
+        // Authenticates the upgrade itself (see `websocket::game_ws_handler`)
+        // from a query-string token or the first frame, not from an
+        // `Authorization` header.
+        .route("/api/v1/games/:game_id/ws", get(game_ws_handler));
+
+    // Optional read-only introspection surface. Compiled and routed only
+    // when the `stub_status` feature is on, so a default build adds zero
+    // routes and zero runtime cost.
+    #[cfg(feature = "stub_status")]
+    let app = app
+        .route(
+            "/api/v1/status/connections",
+            get(blackjack_api::status::connections),
+        )
+        .route("/api/v1/status/games", get(blackjack_api::status::games))
+        .route(
+            "/api/v1/status/queue-depth",
+            get(blackjack_api::status::queue_depth),
+        );
+
+    let app = app
         // Apply version deprecation middleware to add X-API-Deprecated headers
         .layer(axum::middleware::from_fn_with_state(
             state.clone(),
@@ -100,18 +275,29 @@ async fn main() {
         // Attach shared state to all handlers
         .with_state(state);
 
-    // Bind TCP listener to configured host and port
-    // Panics if binding fails (e.g., port already in use)
+    // Bind address configured host and port; TLS (below) and plain HTTP
+    // share the same host:port, since a deployment runs one or the other.
     let addr = format!("{}:{}", app_config.server.host, app_config.server.port);
-    let listener = tokio::net::TcpListener::bind(&addr)
-        .await
-        .expect("Failed to bind server");
 
-    tracing::info!(address = addr, "Server listening");
+    if blackjack_api::tls::tls_enabled(&app_config.tls) {
+        // `[tls]` is present and enabled: provision/renew a Let's Encrypt
+        // certificate via ACME TLS-ALPN-01 and serve HTTPS directly,
+        // removing the need for a reverse proxy in front of this process.
+        let tls_config = app_config.tls.as_ref().expect("checked by tls_enabled");
+        blackjack_api::tls::serve_https(&addr, tls_config, app).await;
+    } else {
+        // No `[tls]` section, or explicitly disabled: unchanged plain-HTTP
+        // behavior.
+        let listener = tokio::net::TcpListener::bind(&addr)
+            .await
+            .expect("Failed to bind server");
+
+        tracing::info!(address = addr, "Server listening");
 
-    // Start the HTTP server
-    // This blocks until the server is shut down (e.g., via SIGTERM/SIGINT)
-    axum::serve(listener, app)
-        .await
-        .expect("Server error");
+        // Start the HTTP server
+        // This blocks until the server is shut down (e.g., via SIGTERM/SIGINT)
+        axum::serve(listener, app)
+            .await
+            .expect("Server error");
+    }
 }
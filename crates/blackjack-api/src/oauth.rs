@@ -0,0 +1,325 @@
+//! OAuth2 authorization-code login for external identity providers
+//!
+//! Adds a second way to authenticate alongside `POST /api/v1/auth/login`'s
+//! email/password flow: a provider-hosted authorization-code exchange that
+//! ends in the exact same [`crate::handlers::LoginResponse`] (access +
+//! refresh token) the rest of the API already expects, so nothing downstream
+//! of login needs to know which path a client used.
+//!
+//! # Flow
+//!
+//! 1. `GET /api/v1/auth/oauth/:provider/authorize` - builds the provider's
+//!    authorization URL with a generated `state` nonce, stored server-side
+//!    with a short TTL, and redirects the client there.
+//! 2. Provider redirects back to
+//!    `GET /api/v1/auth/oauth/:provider/callback?code=...&state=...`.
+//! 3. The handler checks `state` against the store (CSRF protection; a
+//!    state value is single-use and expires quickly), exchanges `code` for
+//!    the provider's tokens, and fetches the user's verified email.
+//! 4. Finds or creates a local user by that email - an OAuth login and a
+//!    password login sharing an email map to the same account - and issues
+//!    the usual JWT access + refresh token pair.
+//!
+//! # Configuration
+//!
+//! Each provider's client id/secret/redirect URI/scopes come from
+//! `AppConfig::oauth_providers`, keyed by provider name, so adding an
+//! OpenID-Connect-compatible provider (e.g. Google) - one whose
+//! `user_info_url` itself returns `email`/`email_verified` - is a config
+//! change, not a code change. GitHub isn't OIDC: its `/user` endpoint has no
+//! `email_verified` field, and a verified primary email needs a second call
+//! to `/user/emails`. Setting a provider's `emails_url` switches to that
+//! second call instead of trusting `user_info_url` directly - see
+//! [`fetch_verified_email`].
+
+use axum::extract::{Path, Query, State};
+use axum::response::{IntoResponse, Redirect};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+use crate::error::ApiError;
+use crate::AppState;
+
+/// How long a generated `state` nonce remains valid, bounding the window an
+/// attacker has to replay a captured authorization redirect.
+const STATE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Per-provider OAuth2 client configuration, keyed by provider name (e.g.
+/// `"google"`, `"github"`) in `AppConfig::oauth_providers`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OAuthProviderConfig {
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_uri: String,
+    pub authorize_url: String,
+    pub token_url: String,
+    pub user_info_url: String,
+    pub scopes: Vec<String>,
+
+    /// Separate endpoint returning the account's email addresses, for
+    /// providers (e.g. GitHub's `/user/emails`) whose `user_info_url`
+    /// doesn't itself report a verified email. `None` for OIDC-compatible
+    /// providers, where `user_info_url`'s own `email`/`email_verified`
+    /// fields are used instead.
+    #[serde(default)]
+    pub emails_url: Option<String>,
+}
+
+/// Tracks outstanding `state` nonces issued by `authorize`, so `callback`
+/// can reject a request whose `state` wasn't one the server handed out
+/// (CSRF) or that has already been consumed/expired.
+#[derive(Default)]
+pub struct OAuthStateStore {
+    pending: Mutex<HashMap<String, (String, Instant)>>,
+}
+
+impl OAuthStateStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Issues a new nonce bound to `provider`.
+    fn issue(&self, provider: &str) -> String {
+        let nonce = Uuid::new_v4().to_string();
+        self.pending
+            .lock()
+            .expect("oauth state store poisoned")
+            .insert(nonce.clone(), (provider.to_string(), Instant::now()));
+        nonce
+    }
+
+    /// Consumes `nonce`, returning `true` if it was valid, unexpired, and
+    /// bound to `provider`. Always removes the entry - valid or not - so a
+    /// `state` value can only ever be accepted once.
+    fn consume(&self, nonce: &str, provider: &str) -> bool {
+        let mut pending = self.pending.lock().expect("oauth state store poisoned");
+        match pending.remove(nonce) {
+            Some((bound_provider, issued_at)) => {
+                bound_provider == provider && issued_at.elapsed() < STATE_TTL
+            }
+            None => false,
+        }
+    }
+}
+
+/// Builds `provider`'s authorization URL and redirects the client to it.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/auth/oauth/:provider/authorize`
+///
+/// # Errors
+///
+/// - **404 Not Found** - unconfigured provider name
+#[tracing::instrument(skip(state))]
+pub async fn authorize(
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let provider_config = provider_config(&state, &provider)?;
+    let nonce = state.oauth_state.issue(&provider);
+
+    let scope = provider_config.scopes.join(" ");
+    let url = format!(
+        "{}?client_id={}&redirect_uri={}&response_type=code&scope={}&state={}",
+        provider_config.authorize_url,
+        urlencoding::encode(&provider_config.client_id),
+        urlencoding::encode(&provider_config.redirect_uri),
+        urlencoding::encode(&scope),
+        urlencoding::encode(&nonce),
+    );
+
+    Ok(Redirect::temporary(&url))
+}
+
+/// Query parameters the provider appends to the callback redirect.
+#[derive(Debug, Deserialize)]
+pub struct CallbackQuery {
+    pub code: String,
+    pub state: String,
+}
+
+/// Exchanges the provider's authorization `code` for a local session.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/auth/oauth/:provider/callback`
+///
+/// # Errors
+///
+/// - **400 Bad Request** - missing/invalid/expired `state` (`INVALID_OAUTH_STATE`)
+/// - **502 Bad Gateway** - the provider's token or user-info exchange failed
+#[tracing::instrument(skip(state, query))]
+pub async fn callback(
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+    Query(query): Query<CallbackQuery>,
+) -> Result<axum::Json<crate::handlers::LoginResponse>, ApiError> {
+    let provider_config = provider_config(&state, &provider)?;
+
+    if !state.oauth_state.consume(&query.state, &provider) {
+        return Err(ApiError::new(
+            axum::http::StatusCode::BAD_REQUEST,
+            "INVALID_OAUTH_STATE",
+            "OAuth state is missing, expired, or was already used",
+        ));
+    }
+
+    let provider_tokens = exchange_code(&provider_config, &query.code).await?;
+    let verified_email = fetch_verified_email(&provider_config, &provider_tokens).await?;
+
+    // Link by verified email: an OAuth login and a password-based login
+    // sharing the same address resolve to one account.
+    let user = state.user_service.find_or_create_by_email(&verified_email)?;
+
+    let (token, expires_in) = crate::handlers::issue_access_token(&state, user.id, &user.email)?;
+    let issued_refresh = state.refresh_tokens.issue(user.id);
+
+    tracing::info!(email = %verified_email, provider = %provider, "OAuth login succeeded");
+
+    Ok(axum::Json(crate::handlers::LoginResponse {
+        token,
+        expires_in,
+        refresh_token: issued_refresh.token,
+    }))
+}
+
+fn provider_config<'a>(
+    state: &'a AppState,
+    provider: &str,
+) -> Result<&'a OAuthProviderConfig, ApiError> {
+    state.config.oauth_providers.get(provider).ok_or_else(|| {
+        ApiError::new(
+            axum::http::StatusCode::NOT_FOUND,
+            "UNKNOWN_OAUTH_PROVIDER",
+            "No OAuth provider configured with this name",
+        )
+    })
+}
+
+/// The provider's token-endpoint response.
+#[derive(Debug, Deserialize)]
+struct ProviderTokenResponse {
+    access_token: String,
+}
+
+async fn exchange_code(
+    provider_config: &OAuthProviderConfig,
+    code: &str,
+) -> Result<ProviderTokenResponse, ApiError> {
+    let response = reqwest::Client::new()
+        .post(&provider_config.token_url)
+        .form(&[
+            ("client_id", provider_config.client_id.as_str()),
+            ("client_secret", provider_config.client_secret.as_str()),
+            ("redirect_uri", provider_config.redirect_uri.as_str()),
+            ("code", code),
+            ("grant_type", "authorization_code"),
+        ])
+        .send()
+        .await
+        .map_err(|err| oauth_gateway_error("token exchange", err))?;
+
+    response
+        .json::<ProviderTokenResponse>()
+        .await
+        .map_err(|err| oauth_gateway_error("token exchange response", err))
+}
+
+/// Minimal shape of a provider's user-info response; providers differ in
+/// exact field names, but `email`/`email_verified` is near-universal across
+/// OpenID-Connect-compatible providers like Google.
+#[derive(Debug, Deserialize, Serialize)]
+struct ProviderUserInfo {
+    email: String,
+    #[serde(default)]
+    email_verified: bool,
+}
+
+/// One entry of GitHub's `GET /user/emails` response.
+#[derive(Debug, Deserialize, Serialize)]
+struct ProviderEmailEntry {
+    email: String,
+    primary: bool,
+    verified: bool,
+}
+
+/// Fetches the caller's verified email from `provider_config`, using
+/// `emails_url` (GitHub-style: a separate list-of-emails endpoint) when
+/// configured, or `user_info_url`'s own `email`/`email_verified` fields
+/// otherwise (OIDC-compatible providers like Google).
+async fn fetch_verified_email(
+    provider_config: &OAuthProviderConfig,
+    tokens: &ProviderTokenResponse,
+) -> Result<String, ApiError> {
+    match &provider_config.emails_url {
+        Some(emails_url) => fetch_verified_email_from_list(emails_url, tokens).await,
+        None => fetch_verified_email_from_user_info(&provider_config.user_info_url, tokens).await,
+    }
+}
+
+async fn fetch_verified_email_from_user_info(
+    user_info_url: &str,
+    tokens: &ProviderTokenResponse,
+) -> Result<String, ApiError> {
+    let user_info = reqwest::Client::new()
+        .get(user_info_url)
+        .bearer_auth(&tokens.access_token)
+        .send()
+        .await
+        .map_err(|err| oauth_gateway_error("user info fetch", err))?
+        .json::<ProviderUserInfo>()
+        .await
+        .map_err(|err| oauth_gateway_error("user info response", err))?;
+
+    if !user_info.email_verified {
+        return Err(ApiError::new(
+            axum::http::StatusCode::FORBIDDEN,
+            "OAUTH_EMAIL_UNVERIFIED",
+            "Identity provider did not report a verified email",
+        ));
+    }
+
+    Ok(user_info.email)
+}
+
+async fn fetch_verified_email_from_list(
+    emails_url: &str,
+    tokens: &ProviderTokenResponse,
+) -> Result<String, ApiError> {
+    let emails = reqwest::Client::new()
+        .get(emails_url)
+        .bearer_auth(&tokens.access_token)
+        // GitHub's API requires a User-Agent on every request.
+        .header("User-Agent", "blackjack-api")
+        .send()
+        .await
+        .map_err(|err| oauth_gateway_error("user info fetch", err))?
+        .json::<Vec<ProviderEmailEntry>>()
+        .await
+        .map_err(|err| oauth_gateway_error("user info response", err))?;
+
+    emails
+        .into_iter()
+        .find(|entry| entry.primary && entry.verified)
+        .map(|entry| entry.email)
+        .ok_or_else(|| {
+            ApiError::new(
+                axum::http::StatusCode::FORBIDDEN,
+                "OAUTH_EMAIL_UNVERIFIED",
+                "Identity provider did not report a verified primary email",
+            )
+        })
+}
+
+fn oauth_gateway_error(step: &str, err: reqwest::Error) -> ApiError {
+    tracing::error!(step, error = ?err, "OAuth provider request failed");
+    ApiError::new(
+        axum::http::StatusCode::BAD_GATEWAY,
+        "OAUTH_PROVIDER_ERROR",
+        "Failed to complete authentication with the identity provider",
+    )
+}
@@ -0,0 +1,174 @@
+//! Argon2id password hashing
+//!
+//! `register_user` used to store a placeholder hash and `login` compared
+//! plaintext, so a database leak would hand over every password as-is. This
+//! module hashes with Argon2id and stores the full
+//! [PHC string format](https://github.com/P-H-C/phc-string-format) (e.g.
+//! `$argon2id$v=19$m=19456,t=2,p=1$<b64salt>$<b64hash>`), which embeds the
+//! parameters the hash was created with - so a later change to
+//! `PasswordConfig` doesn't invalidate already-stored hashes.
+//!
+//! `handlers::register_user` and `handlers::confirm_password_reset` call
+//! [`hash_password`] and hand only the resulting PHC string to
+//! `UserService` (in the `blackjack_service` crate). No handler in this
+//! crate calls [`verify_and_rehash_if_needed`]: `handlers::login` passes the
+//! plaintext password to `UserService::login`, and no rehashed value is
+//! stored on login.
+
+use argon2::password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Argon2, Params, Version};
+
+/// Argon2id cost parameters.
+///
+/// Defaults follow the OWASP baseline recommendation (m=19456 KiB, t=2,
+/// p=1). Configurable via `AppConfig::password` so operators can raise the
+/// cost as hardware improves without a code change.
+#[derive(Debug, Clone, Copy)]
+pub struct PasswordConfig {
+    /// Memory cost in KiB.
+    pub memory_kib: u32,
+    /// Number of iterations.
+    pub iterations: u32,
+    /// Degree of parallelism.
+    pub parallelism: u32,
+}
+
+impl Default for PasswordConfig {
+    fn default() -> Self {
+        PasswordConfig {
+            memory_kib: 19_456,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+impl PasswordConfig {
+    fn argon2(&self) -> Argon2<'static> {
+        let params = Params::new(self.memory_kib, self.iterations, self.parallelism, None)
+            .expect("Argon2 params validated by PasswordConfig's own fields");
+        Argon2::new(argon2::Algorithm::Argon2id, Version::V0x13, params)
+    }
+}
+
+/// Hashes `password` with a fresh random salt, returning the full PHC
+/// string to store in the user record.
+pub fn hash_password(password: &str, config: &PasswordConfig) -> Result<String, PasswordError> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = config
+        .argon2()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|_| PasswordError::HashingFailed)?;
+    Ok(hash.to_string())
+}
+
+/// Verifies `password` against a stored PHC string in constant time.
+///
+/// On success, also reports whether the stored hash's embedded parameters
+/// are weaker than `current_config` - if so the caller should re-hash with
+/// [`hash_password`] and persist the result, transparently upgrading
+/// credentials hashed under an older, weaker configuration.
+pub fn verify_and_rehash_if_needed(
+    password: &str,
+    stored_phc: &str,
+    current_config: &PasswordConfig,
+) -> Result<RehashOutcome, PasswordError> {
+    let parsed = PasswordHash::new(stored_phc).map_err(|_| PasswordError::MalformedHash)?;
+
+    current_config
+        .argon2()
+        .verify_password(password.as_bytes(), &parsed)
+        .map_err(|_| PasswordError::Mismatch)?;
+
+    let rehash = if is_weaker_than(&parsed, current_config) {
+        Some(hash_password(password, current_config)?)
+    } else {
+        None
+    };
+
+    Ok(RehashOutcome { rehash })
+}
+
+/// Result of a successful password verification.
+pub struct RehashOutcome {
+    /// `Some(new_phc_string)` if the stored hash's parameters were weaker
+    /// than `current_config` and should be persisted in place of the old
+    /// one; `None` if the stored hash is already at or above current cost.
+    pub rehash: Option<String>,
+}
+
+fn is_weaker_than(stored: &PasswordHash<'_>, current: &PasswordConfig) -> bool {
+    let stored_params = match argon2::Params::try_from(stored) {
+        Ok(params) => params,
+        // An unparseable params block is itself a reason to rehash onto a
+        // known-good configuration.
+        Err(_) => return true,
+    };
+
+    stored_params.m_cost() < current.memory_kib
+        || stored_params.t_cost() < current.iterations
+        || stored_params.p_cost() < current.parallelism
+}
+
+/// Failure modes for hashing/verification.
+///
+/// Deliberately doesn't distinguish "unknown email" from "wrong password"
+/// at this layer - callers map both to the same `INVALID_CREDENTIALS` API
+/// error so a failed login doesn't leak which one was wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordError {
+    HashingFailed,
+    MalformedHash,
+    Mismatch,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cheaper than `PasswordConfig::default()` so the test suite doesn't
+    /// pay the OWASP-baseline Argon2id cost on every run.
+    fn test_config() -> PasswordConfig {
+        PasswordConfig {
+            memory_kib: 8,
+            iterations: 1,
+            parallelism: 1,
+        }
+    }
+
+    #[test]
+    fn verifies_correct_password() {
+        let config = test_config();
+        let hash = hash_password("correct horse battery staple", &config).unwrap();
+        let outcome = verify_and_rehash_if_needed("correct horse battery staple", &hash, &config).unwrap();
+        assert!(outcome.rehash.is_none());
+    }
+
+    #[test]
+    fn rejects_wrong_password() {
+        let config = test_config();
+        let hash = hash_password("correct horse battery staple", &config).unwrap();
+        let err = verify_and_rehash_if_needed("wrong password", &hash, &config).unwrap_err();
+        assert_eq!(err, PasswordError::Mismatch);
+    }
+
+    #[test]
+    fn rejects_malformed_hash() {
+        let config = test_config();
+        let err = verify_and_rehash_if_needed("anything", "not-a-phc-string", &config).unwrap_err();
+        assert_eq!(err, PasswordError::MalformedHash);
+    }
+
+    #[test]
+    fn flags_rehash_when_config_strengthens() {
+        let weak_config = test_config();
+        let hash = hash_password("correct horse battery staple", &weak_config).unwrap();
+
+        let mut stronger_config = weak_config;
+        stronger_config.memory_kib = weak_config.memory_kib * 2;
+
+        let outcome =
+            verify_and_rehash_if_needed("correct horse battery staple", &hash, &stronger_config).unwrap();
+        assert!(outcome.rehash.is_some());
+    }
+}
@@ -0,0 +1,432 @@
+//! SQLite persistence layer
+//!
+//! `ready_check` used to literally report `"future_sqlite": "pending"` -
+//! every game and user lived only in `GameService`/`UserService`'s
+//! in-memory maps and vanished on restart. This module adds a SQLx-backed
+//! store (SQLite by default) with versioned migrations for users, games,
+//! players, and card histories, behind a [`Repository`] trait so the
+//! in-memory store remains available as an alternate backend (selected via
+//! `AppConfig::persistence.backend`) for fast tests.
+//!
+//! # Wiring
+//!
+//! The repository lives in `AppState::repository` and is called directly
+//! by handlers. `UserService` (in the `blackjack_service` crate) owns user
+//! accounts and is not given the repository: `register_user`, `login`, and
+//! the verification/reset handlers go through `UserService` only, so no
+//! handler in this crate reads or writes the `users` table.
+//!
+//! `GameService` keeps its in-memory maps as the authoritative source for
+//! live gameplay - they're what every handler's response is built from, and
+//! they're what the WebSocket/SSE broadcasts read - but the game handlers in
+//! `handlers::create_game`, `join_game`, `leave_game`, `draw_card`,
+//! `finish_game`, and `stand` additionally write through to this
+//! [`Repository`] after their state change, so the `games`, `players`, and
+//! `card_history` tables mirror what those handlers change in memory.
+//! `set_ace_value` and `start_game` don't write through, so a point total
+//! changed by an Ace or by the initial deal isn't reflected in `players`
+//! until that player's next draw or stand. A
+//! write-through failure is logged and otherwise ignored (the in-memory
+//! state already answered the request, the same fire-and-forget idiom
+//! `handlers::publish_game_event` uses for broadcast failures) rather than
+//! turned into an error response, since a database hiccup shouldn't break
+//! gameplay that's still live in memory.
+//!
+//! This does not yet cover restart recovery: `GameService` doesn't load its
+//! maps back from these tables on startup, so an in-flight game's live state
+//! is still lost across a restart even though its last-known row survives in
+//! the database. Rehydrating `GameService` from the repository on startup
+//! would require a constructor change in `blackjack_service` itself, outside
+//! this crate.
+//!
+//! # Migrations
+//!
+//! Applied automatically on startup via `sqlx::migrate!`, which tracks
+//! already-applied migrations in its own bookkeeping table, so restarting
+//! against an existing database is a no-op.
+
+use async_trait::async_trait;
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// A stored user record.
+#[derive(Debug, Clone)]
+pub struct StoredUser {
+    pub id: Uuid,
+    pub email: String,
+    pub password_hash: String,
+    pub verified: bool,
+}
+
+/// A stored player row: one game's view of one player's table state.
+#[derive(Debug, Clone)]
+pub struct StoredPlayer {
+    pub game_id: Uuid,
+    pub email: String,
+    pub points: i64,
+    pub busted: bool,
+    pub standing: bool,
+}
+
+/// A single recorded card draw, for the `card_history` audit trail.
+#[derive(Debug, Clone)]
+pub struct StoredCardDraw {
+    pub game_id: Uuid,
+    pub player_email: String,
+    pub card_id: String,
+    pub card_name: String,
+    pub card_value: i64,
+    pub card_suit: String,
+}
+
+/// Durable storage for users, games, players, and card histories.
+///
+/// Implemented by [`SqliteRepository`] for production and
+/// [`InMemoryRepository`] for fast tests; `AppState` holds an
+/// `Arc<dyn Repository>` rather than a concrete type so the backend is a
+/// config choice, not a code change.
+#[async_trait]
+pub trait Repository: Send + Sync {
+    async fn create_user(
+        &self,
+        email: &str,
+        password_hash: &str,
+    ) -> Result<StoredUser, PersistenceError>;
+    async fn get_user(&self, id: Uuid) -> Result<StoredUser, PersistenceError>;
+    async fn get_user_by_email(&self, email: &str) -> Result<StoredUser, PersistenceError>;
+    async fn set_password_hash(&self, id: Uuid, password_hash: &str) -> Result<(), PersistenceError>;
+    async fn mark_verified(&self, id: Uuid) -> Result<(), PersistenceError>;
+
+    /// Records a newly created game's row. Called once, from
+    /// `handlers::create_game`, right after `GameService::create_game`
+    /// succeeds.
+    async fn create_game(&self, game_id: Uuid, creator_id: Uuid) -> Result<(), PersistenceError>;
+
+    /// Marks a game's row as finished. Called from `handlers::finish_game`
+    /// and `handlers::stand` (when standing ends the game).
+    async fn mark_game_finished(&self, game_id: Uuid) -> Result<(), PersistenceError>;
+
+    /// Inserts or updates a player's row for a game, keyed on
+    /// `(game_id, email)`. Called from `handlers::create_game`,
+    /// `join_game`, `draw_card`, and `stand` after those handlers change a
+    /// player's points, busted state, or standing state (`set_ace_value`
+    /// and `start_game` don't call it).
+    async fn upsert_player(&self, player: StoredPlayer) -> Result<(), PersistenceError>;
+
+    /// Removes a player's row for a game. Called from `handlers::leave_game`.
+    async fn remove_player(&self, game_id: Uuid, email: &str) -> Result<(), PersistenceError>;
+
+    /// Appends one drawn card to `card_history`. Called from
+    /// `handlers::draw_card` after a successful draw.
+    async fn record_card_draw(&self, draw: StoredCardDraw) -> Result<(), PersistenceError>;
+
+    /// Connectivity probe for `ready_check` - cheap enough to run on every
+    /// readiness poll.
+    async fn ping(&self) -> Result<(), PersistenceError>;
+}
+
+/// Failure reading or writing through a [`Repository`].
+#[derive(Debug, Clone)]
+pub enum PersistenceError {
+    NotFound,
+    Backend(String),
+}
+
+/// Opens the SQLite connection pool at `database_url` and applies any
+/// pending migrations.
+///
+/// # Panics
+///
+/// Panics if the pool can't be created or a migration fails to apply -
+/// this runs once at startup and the server can't usefully serve traffic
+/// against a database it couldn't migrate, so fail-fast matches how
+/// `AppConfig::from_file` is already handled in `main`.
+pub async fn connect(database_url: &str) -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(5)
+        .connect(database_url)
+        .await
+        .expect("Failed to connect to SQLite database");
+
+    sqlx::migrate!("src/persistence/migrations")
+        .run(&pool)
+        .await
+        .expect("Failed to apply database migrations");
+
+    pool
+}
+
+/// SQLx-backed [`Repository`] implementation.
+pub struct SqliteRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        SqliteRepository { pool }
+    }
+}
+
+#[async_trait]
+impl Repository for SqliteRepository {
+    async fn create_user(
+        &self,
+        email: &str,
+        password_hash: &str,
+    ) -> Result<StoredUser, PersistenceError> {
+        let id = Uuid::new_v4();
+        sqlx::query("INSERT INTO users (id, email, password_hash) VALUES (?, ?, ?)")
+            .bind(id.to_string())
+            .bind(email)
+            .bind(password_hash)
+            .execute(&self.pool)
+            .await
+            .map_err(|err| PersistenceError::Backend(err.to_string()))?;
+
+        Ok(StoredUser {
+            id,
+            email: email.to_string(),
+            password_hash: password_hash.to_string(),
+            verified: false,
+        })
+    }
+
+    async fn get_user(&self, id: Uuid) -> Result<StoredUser, PersistenceError> {
+        let row: (String, String, String, bool) = sqlx::query_as(
+            "SELECT id, email, password_hash, verified FROM users WHERE id = ?",
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| PersistenceError::Backend(err.to_string()))?
+        .ok_or(PersistenceError::NotFound)?;
+
+        Ok(StoredUser {
+            id,
+            email: row.1,
+            password_hash: row.2,
+            verified: row.3,
+        })
+    }
+
+    async fn get_user_by_email(&self, email: &str) -> Result<StoredUser, PersistenceError> {
+        let row: (String, String, String, bool) = sqlx::query_as(
+            "SELECT id, email, password_hash, verified FROM users WHERE email = ?",
+        )
+        .bind(email)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| PersistenceError::Backend(err.to_string()))?
+        .ok_or(PersistenceError::NotFound)?;
+
+        Ok(StoredUser {
+            id: Uuid::parse_str(&row.0).map_err(|err| PersistenceError::Backend(err.to_string()))?,
+            email: row.1,
+            password_hash: row.2,
+            verified: row.3,
+        })
+    }
+
+    async fn set_password_hash(&self, id: Uuid, password_hash: &str) -> Result<(), PersistenceError> {
+        sqlx::query("UPDATE users SET password_hash = ? WHERE id = ?")
+            .bind(password_hash)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| PersistenceError::Backend(err.to_string()))?;
+        Ok(())
+    }
+
+    async fn mark_verified(&self, id: Uuid) -> Result<(), PersistenceError> {
+        sqlx::query("UPDATE users SET verified = 1 WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| PersistenceError::Backend(err.to_string()))?;
+        Ok(())
+    }
+
+    async fn create_game(&self, game_id: Uuid, creator_id: Uuid) -> Result<(), PersistenceError> {
+        sqlx::query("INSERT INTO games (id, creator_id) VALUES (?, ?)")
+            .bind(game_id.to_string())
+            .bind(creator_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| PersistenceError::Backend(err.to_string()))?;
+        Ok(())
+    }
+
+    async fn mark_game_finished(&self, game_id: Uuid) -> Result<(), PersistenceError> {
+        sqlx::query("UPDATE games SET finished = 1 WHERE id = ?")
+            .bind(game_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|err| PersistenceError::Backend(err.to_string()))?;
+        Ok(())
+    }
+
+    async fn upsert_player(&self, player: StoredPlayer) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "INSERT INTO players (game_id, email, points, busted, standing) VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT (game_id, email) DO UPDATE SET
+                 points = excluded.points,
+                 busted = excluded.busted,
+                 standing = excluded.standing",
+        )
+        .bind(player.game_id.to_string())
+        .bind(&player.email)
+        .bind(player.points)
+        .bind(player.busted)
+        .bind(player.standing)
+        .execute(&self.pool)
+        .await
+        .map_err(|err| PersistenceError::Backend(err.to_string()))?;
+        Ok(())
+    }
+
+    async fn remove_player(&self, game_id: Uuid, email: &str) -> Result<(), PersistenceError> {
+        sqlx::query("DELETE FROM players WHERE game_id = ? AND email = ?")
+            .bind(game_id.to_string())
+            .bind(email)
+            .execute(&self.pool)
+            .await
+            .map_err(|err| PersistenceError::Backend(err.to_string()))?;
+        Ok(())
+    }
+
+    async fn record_card_draw(&self, draw: StoredCardDraw) -> Result<(), PersistenceError> {
+        sqlx::query(
+            "INSERT INTO card_history (game_id, player_email, card_id, card_name, card_value, card_suit)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(draw.game_id.to_string())
+        .bind(&draw.player_email)
+        .bind(&draw.card_id)
+        .bind(&draw.card_name)
+        .bind(draw.card_value)
+        .bind(&draw.card_suit)
+        .execute(&self.pool)
+        .await
+        .map_err(|err| PersistenceError::Backend(err.to_string()))?;
+        Ok(())
+    }
+
+    async fn ping(&self) -> Result<(), PersistenceError> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map_err(|err| PersistenceError::Backend(err.to_string()))?;
+        Ok(())
+    }
+}
+
+/// In-memory [`Repository`] implementation, selected instead of
+/// [`SqliteRepository`] when `AppConfig::persistence.backend` is
+/// `"memory"`, so test suites stay fast and don't need a SQLite file.
+#[derive(Default)]
+pub struct InMemoryRepository {
+    users: std::sync::Mutex<Vec<StoredUser>>,
+    games: std::sync::Mutex<std::collections::HashMap<Uuid, bool>>,
+    players: std::sync::Mutex<Vec<StoredPlayer>>,
+    card_history: std::sync::Mutex<Vec<StoredCardDraw>>,
+}
+
+impl InMemoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl Repository for InMemoryRepository {
+    async fn create_user(
+        &self,
+        email: &str,
+        password_hash: &str,
+    ) -> Result<StoredUser, PersistenceError> {
+        let user = StoredUser {
+            id: Uuid::new_v4(),
+            email: email.to_string(),
+            password_hash: password_hash.to_string(),
+            verified: false,
+        };
+        self.users.lock().expect("in-memory repository poisoned").push(user.clone());
+        Ok(user)
+    }
+
+    async fn get_user(&self, id: Uuid) -> Result<StoredUser, PersistenceError> {
+        self.users
+            .lock()
+            .expect("in-memory repository poisoned")
+            .iter()
+            .find(|user| user.id == id)
+            .cloned()
+            .ok_or(PersistenceError::NotFound)
+    }
+
+    async fn get_user_by_email(&self, email: &str) -> Result<StoredUser, PersistenceError> {
+        self.users
+            .lock()
+            .expect("in-memory repository poisoned")
+            .iter()
+            .find(|user| user.email == email)
+            .cloned()
+            .ok_or(PersistenceError::NotFound)
+    }
+
+    async fn set_password_hash(&self, id: Uuid, password_hash: &str) -> Result<(), PersistenceError> {
+        let mut users = self.users.lock().expect("in-memory repository poisoned");
+        let user = users.iter_mut().find(|user| user.id == id).ok_or(PersistenceError::NotFound)?;
+        user.password_hash = password_hash.to_string();
+        Ok(())
+    }
+
+    async fn mark_verified(&self, id: Uuid) -> Result<(), PersistenceError> {
+        let mut users = self.users.lock().expect("in-memory repository poisoned");
+        let user = users.iter_mut().find(|user| user.id == id).ok_or(PersistenceError::NotFound)?;
+        user.verified = true;
+        Ok(())
+    }
+
+    async fn create_game(&self, game_id: Uuid, _creator_id: Uuid) -> Result<(), PersistenceError> {
+        self.games.lock().expect("in-memory repository poisoned").insert(game_id, false);
+        Ok(())
+    }
+
+    async fn mark_game_finished(&self, game_id: Uuid) -> Result<(), PersistenceError> {
+        let mut games = self.games.lock().expect("in-memory repository poisoned");
+        let finished = games.get_mut(&game_id).ok_or(PersistenceError::NotFound)?;
+        *finished = true;
+        Ok(())
+    }
+
+    async fn upsert_player(&self, player: StoredPlayer) -> Result<(), PersistenceError> {
+        let mut players = self.players.lock().expect("in-memory repository poisoned");
+        match players
+            .iter_mut()
+            .find(|row| row.game_id == player.game_id && row.email == player.email)
+        {
+            Some(existing) => *existing = player,
+            None => players.push(player),
+        }
+        Ok(())
+    }
+
+    async fn remove_player(&self, game_id: Uuid, email: &str) -> Result<(), PersistenceError> {
+        self.players
+            .lock()
+            .expect("in-memory repository poisoned")
+            .retain(|row| !(row.game_id == game_id && row.email == email));
+        Ok(())
+    }
+
+    async fn record_card_draw(&self, draw: StoredCardDraw) -> Result<(), PersistenceError> {
+        self.card_history.lock().expect("in-memory repository poisoned").push(draw);
+        Ok(())
+    }
+
+    async fn ping(&self) -> Result<(), PersistenceError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+//! Refresh-token subsystem with rotation and revocation
+//!
+//! `login` used to mint a single 24h JWT with no way to invalidate it
+//! short of waiting out its `exp`. This module backs a shorter-lived access
+//! token (~15 min, via `AppConfig::jwt.access_token_expiration_minutes`)
+//! with an opaque, server-tracked refresh token, so clients can stay logged
+//! in indefinitely without carrying a long-lived bearer token around.
+//!
+//! # Storage
+//!
+//! Only a refresh token's SHA-256 hash is ever persisted - the random
+//! 256-bit value itself exists solely in the response sent to the client
+//! and is never stored, so a database leak doesn't hand over usable
+//! credentials.
+//!
+//! # Rotation
+//!
+//! Each call to `POST /api/v1/auth/refresh` consumes the presented token
+//! and issues a new one in its place (same `family_id`, new hash). Once
+//! consumed, a token's hash is removed from the store, so presenting it a
+//! second time - e.g. a stolen token racing the legitimate client - fails
+//! lookup and is rejected as `REFRESH_TOKEN_INVALID`. `logout` drops the
+//! whole family so every token descended from a single login stops working
+//! in one call.
+
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+use uuid::Uuid;
+
+/// A single issued (not yet consumed) refresh token's server-side record.
+struct RefreshTokenRecord {
+    user_id: Uuid,
+    family_id: Uuid,
+    expires_at: SystemTime,
+}
+
+/// Tracks live refresh tokens by the SHA-256 hash of their value.
+#[derive(Default)]
+pub struct RefreshTokenStore {
+    tokens: Mutex<HashMap<String, RefreshTokenRecord>>,
+}
+
+/// A freshly issued refresh token, returned to the client exactly once.
+pub struct IssuedRefreshToken {
+    /// The opaque value to send to the client; never stored server-side.
+    pub token: String,
+    pub family_id: Uuid,
+}
+
+/// Why a presented refresh token was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshTokenError {
+    /// Unknown, already-consumed, or forged token.
+    Invalid,
+    /// Known but past its expiry.
+    Expired,
+}
+
+const REFRESH_TOKEN_TTL: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+impl RefreshTokenStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Issues a brand-new refresh token, starting a new family for `user_id`.
+    pub fn issue(&self, user_id: Uuid) -> IssuedRefreshToken {
+        self.issue_in_family(user_id, Uuid::new_v4())
+    }
+
+    fn issue_in_family(&self, user_id: Uuid, family_id: Uuid) -> IssuedRefreshToken {
+        let token = random_token();
+        let hash = hash_token(&token);
+        let expires_at = SystemTime::now() + REFRESH_TOKEN_TTL;
+
+        self.tokens.lock().expect("refresh token store poisoned").insert(
+            hash,
+            RefreshTokenRecord {
+                user_id,
+                family_id,
+                expires_at,
+            },
+        );
+
+        IssuedRefreshToken { token, family_id }
+    }
+
+    /// Validates `presented_token`, consumes it, and issues a replacement in
+    /// the same family.
+    ///
+    /// Returns the owning `user_id` (so the caller can mint a new access
+    /// token) plus the replacement refresh token.
+    pub fn rotate(
+        &self,
+        presented_token: &str,
+    ) -> Result<(Uuid, IssuedRefreshToken), RefreshTokenError> {
+        let hash = hash_token(presented_token);
+
+        let record = {
+            let mut tokens = self.tokens.lock().expect("refresh token store poisoned");
+            tokens.remove(&hash).ok_or(RefreshTokenError::Invalid)?
+        };
+
+        if record.expires_at < SystemTime::now() {
+            return Err(RefreshTokenError::Expired);
+        }
+
+        let issued = self.issue_in_family(record.user_id, record.family_id);
+        Ok((record.user_id, issued))
+    }
+
+    /// Revokes every token in `family_id`'s family - used by `logout` so a
+    /// single call invalidates every refresh token descended from one
+    /// login, not just the one the client happened to present.
+    pub fn revoke_family(&self, family_id: Uuid) {
+        self.tokens
+            .lock()
+            .expect("refresh token store poisoned")
+            .retain(|_, record| record.family_id != family_id);
+    }
+
+    /// Resolves the family a presented token belongs to, without consuming
+    /// it - used by `logout` to find the family to revoke.
+    pub fn family_of(&self, presented_token: &str) -> Option<Uuid> {
+        let hash = hash_token(presented_token);
+        self.tokens
+            .lock()
+            .expect("refresh token store poisoned")
+            .get(&hash)
+            .map(|record| record.family_id)
+    }
+}
+
+fn random_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+fn hash_token(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotate_returns_owning_user_and_a_fresh_token() {
+        let store = RefreshTokenStore::new();
+        let user_id = Uuid::new_v4();
+        let issued = store.issue(user_id);
+
+        let (rotated_user_id, replacement) = store.rotate(&issued.token).unwrap();
+
+        assert_eq!(rotated_user_id, user_id);
+        assert_eq!(replacement.family_id, issued.family_id);
+        assert_ne!(replacement.token, issued.token);
+    }
+
+    #[test]
+    fn a_consumed_token_cannot_be_rotated_again() {
+        let store = RefreshTokenStore::new();
+        let issued = store.issue(Uuid::new_v4());
+
+        store.rotate(&issued.token).unwrap();
+
+        assert_eq!(store.rotate(&issued.token).unwrap_err(), RefreshTokenError::Invalid);
+    }
+
+    #[test]
+    fn unknown_token_is_rejected() {
+        let store = RefreshTokenStore::new();
+        assert_eq!(
+            store.rotate("not-a-real-token").unwrap_err(),
+            RefreshTokenError::Invalid
+        );
+    }
+
+    #[test]
+    fn revoking_a_family_invalidates_every_descendant() {
+        let store = RefreshTokenStore::new();
+        let issued = store.issue(Uuid::new_v4());
+        let (_, rotated) = store.rotate(&issued.token).unwrap();
+
+        store.revoke_family(rotated.family_id);
+
+        assert_eq!(store.rotate(&rotated.token).unwrap_err(), RefreshTokenError::Invalid);
+    }
+
+    #[test]
+    fn family_of_resolves_without_consuming_the_token() {
+        let store = RefreshTokenStore::new();
+        let issued = store.issue(Uuid::new_v4());
+
+        assert_eq!(store.family_of(&issued.token), Some(issued.family_id));
+        // Still usable afterwards - `family_of` must not have consumed it.
+        assert!(store.rotate(&issued.token).is_ok());
+    }
+
+    #[test]
+    fn family_of_is_none_for_unknown_token() {
+        let store = RefreshTokenStore::new();
+        assert_eq!(store.family_of("not-a-real-token"), None);
+    }
+}
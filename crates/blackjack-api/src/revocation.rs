@@ -0,0 +1,64 @@
+//! JWT revocation list
+//!
+//! A signed `Claims` token is otherwise valid until its `exp` no matter
+//! what happens server-side. This module gives the server a way to reject
+//! a specific token early - on logout, or if it's reported leaked - by
+//! tracking its `jti` in a blocklist that the auth middleware consults on
+//! every request.
+//!
+//! Entries are evicted once their original `exp` passes, the same
+//! sliding-window eviction idea `RateLimiter` already uses to keep its
+//! per-player tracking from growing unbounded: a blocked token is no threat
+//! once it would have expired anyway, so there's no reason to keep it.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Tracks revoked token identifiers (`jti`) until their original expiration.
+#[derive(Default)]
+pub struct RevocationList {
+    /// `jti` -> the token's original `exp` (Unix timestamp), used to evict
+    /// entries that can no longer matter.
+    revoked: Mutex<HashMap<String, usize>>,
+}
+
+impl RevocationList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Blocklists `jti` until `exp` (Unix timestamp seconds) passes.
+    pub fn revoke(&self, jti: String, exp: usize) {
+        self.revoked.lock().expect("revocation list poisoned").insert(jti, exp);
+    }
+
+    /// Returns `true` if `jti` has been revoked and hasn't naturally expired.
+    ///
+    /// Opportunistically evicts expired entries on each call, the same way
+    /// `RateLimiter` prunes stale windows on access rather than running a
+    /// background sweep.
+    pub fn is_revoked(&self, jti: &str) -> bool {
+        let now = current_unix_time();
+        let mut revoked = self.revoked.lock().expect("revocation list poisoned");
+        revoked.retain(|_, exp| *exp > now);
+        revoked.contains_key(jti)
+    }
+
+    /// Number of entries currently tracked, for tests and introspection.
+    pub fn len(&self) -> usize {
+        self.revoked.lock().expect("revocation list poisoned").len()
+    }
+
+    /// Whether no entries are currently tracked.
+    pub fn is_empty(&self) -> bool {
+        self.revoked.lock().expect("revocation list poisoned").is_empty()
+    }
+}
+
+fn current_unix_time() -> usize {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock before UNIX epoch")
+        .as_secs() as usize
+}
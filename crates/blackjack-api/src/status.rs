@@ -0,0 +1,91 @@
+//! Compile-time status/introspection endpoints
+//!
+//! Gated entirely behind the `stub_status` Cargo feature: when the feature
+//! is off, this module is not compiled at all, no routes are registered in
+//! `main.rs`, and the default API is byte-for-byte what it was before this
+//! file existed. When it's on, it exposes a lightweight read-only view of
+//! live server activity for operators who want a health picture without
+//! standing up a full metrics stack.
+//!
+//! # Endpoints
+//!
+//! - `GET /api/v1/status/connections` - total active WebSocket connections
+//! - `GET /api/v1/status/games` - connections and WebSocket subscriber count
+//!   per `game_id`
+//! - `GET /api/v1/status/queue-depth` - deepest pending action queue length
+//!   observed across all games
+//!
+//! # Authentication
+//!
+//! None - these are intended for operator/monitoring use behind whatever
+//! network boundary already protects the deployment (the feature itself is
+//! the access control: it's off by default).
+
+#![cfg(feature = "stub_status")]
+
+use axum::extract::State;
+use axum::Json;
+use serde::Serialize;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::AppState;
+
+/// Total live WebSocket connections across every game.
+#[derive(Debug, Serialize)]
+pub struct ConnectionsResponse {
+    /// Sum of WebSocket subscribers across all games.
+    pub total_connections: usize,
+}
+
+/// Returns the total number of connected WebSocket clients.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/status/connections`
+#[tracing::instrument(skip(state))]
+pub async fn connections(State(state): State<AppState>) -> Json<ConnectionsResponse> {
+    Json(ConnectionsResponse {
+        total_connections: state.game_broadcast.total_subscriber_count(),
+    })
+}
+
+/// Per-game breakdown of WebSocket subscriber counts.
+#[derive(Debug, Serialize)]
+pub struct GamesResponse {
+    /// `game_id` -> number of connected WebSocket clients for that game.
+    pub games: HashMap<Uuid, usize>,
+}
+
+/// Returns the connected WebSocket client count broken down by game.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/status/games`
+#[tracing::instrument(skip(state))]
+pub async fn games(State(state): State<AppState>) -> Json<GamesResponse> {
+    Json(GamesResponse {
+        games: state.game_broadcast.subscriber_counts_by_game(),
+    })
+}
+
+/// Deepest pending-action backlog across all games.
+#[derive(Debug, Serialize)]
+pub struct QueueDepthResponse {
+    /// The largest number of requests recorded in any single rate-limiter
+    /// window right now, used here as a proxy for how backed up a game's
+    /// action queue is getting.
+    pub deepest_pending_queue: usize,
+}
+
+/// Returns the deepest pending action queue length observed across games.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/status/queue-depth`
+#[tracing::instrument(skip(state))]
+pub async fn queue_depth(State(state): State<AppState>) -> Json<QueueDepthResponse> {
+    Json(QueueDepthResponse {
+        deepest_pending_queue: state.rate_limiter.deepest_window_len(),
+    })
+}
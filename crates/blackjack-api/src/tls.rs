@@ -0,0 +1,83 @@
+//! Built-in ACME/Let's Encrypt TLS termination
+//!
+//! `main.rs` historically bound a plain `TcpListener` and served cleartext
+//! HTTP, leaving CORS/TLS to be handled by whatever reverse proxy sat in
+//! front of it. This module lets small self-hosted deployments skip that
+//! proxy entirely: when a `[tls]` section is present in configuration, the
+//! server provisions and auto-renews its own certificate via the ACME
+//! TLS-ALPN-01 challenge and serves HTTPS directly.
+//!
+//! # Configuration
+//!
+//! ```toml
+//! [tls]
+//! enabled = true
+//! domains = ["blackjack.example.com"]
+//! contact_email = "ops@example.com"
+//! cache_dir = "./tls-cache"
+//! ```
+//!
+//! When `[tls]` is absent or `enabled = false`, `main.rs` falls back to
+//! plain HTTP exactly as before - only [`tls_enabled`] is consulted, and
+//! [`serve_https`] is never called.
+
+use axum::Router;
+use tokio_rustls_acme::{caches::DirCache, AcmeConfig};
+
+use crate::config::TlsConfig;
+
+/// Serves `app` over HTTPS on `addr`, provisioning and renewing a
+/// Let's Encrypt certificate for `tls_config.domains` via the TLS-ALPN-01
+/// challenge.
+///
+/// Runs until the server errors or is shut down; mirrors the "blocks until
+/// shutdown" contract of `axum::serve` used for the plain-HTTP path so
+/// `main` can treat both branches the same way.
+///
+/// # Panics
+///
+/// Panics if `addr` can't be bound, matching the fail-fast startup
+/// behavior the plain-HTTP path already has for a bind failure.
+pub async fn serve_https(addr: &str, tls_config: &TlsConfig, app: Router) {
+    let mut acme_state = AcmeConfig::new(tls_config.domains.clone())
+        .contact([format!("mailto:{}", tls_config.contact_email)])
+        .cache(DirCache::new(tls_config.cache_dir.clone()))
+        .directory_lets_encrypt(true)
+        .state();
+
+    let acceptor = acme_state.axum_acceptor(acme_state.default_rustls_config());
+
+    // Certificate issuance/renewal events are logged but non-fatal: a
+    // transient ACME failure should not take the server down, it should
+    // just retry on the next renewal tick against the last-known-good cert.
+    tokio::spawn(async move {
+        loop {
+            match acme_state.next().await {
+                Some(Ok(ok)) => tracing::info!(?ok, "ACME certificate event"),
+                Some(Err(err)) => tracing::warn!(error = ?err, "ACME certificate renewal error"),
+                None => break,
+            }
+        }
+    });
+
+    tracing::info!(
+        address = addr,
+        domains = ?tls_config.domains,
+        "Server listening (HTTPS via ACME TLS-ALPN-01)"
+    );
+
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .expect("Failed to bind TLS listener");
+
+    axum_server::from_tcp(listener.into_std().expect("listener convertible to std"))
+        .acceptor(acceptor)
+        .serve(app.into_make_service())
+        .await
+        .expect("HTTPS server error");
+}
+
+/// Whether TLS should be used for this run, per loaded configuration.
+pub fn tls_enabled(tls_config: &Option<TlsConfig>) -> bool {
+    tls_config.as_ref().is_some_and(|cfg| cfg.enabled)
+}
@@ -0,0 +1,378 @@
+//! WebSocket subsystem for real-time game state push
+//!
+//! Clients historically had to poll `GET /api/v1/games/:game_id` to notice
+//! when another player acted or the dealer drew. This module adds a push
+//! channel: every connected client for a game receives a serialized
+//! `GameStateResponse` each time the game's state changes, tagged with a
+//! [`GameEventKind`] (`card_drawn`/`ace_value_changed`/`turn_changed`/
+//! `game_finished`) so clients can dispatch without inspecting the payload.
+//!
+//! # Endpoint
+//!
+//! `GET /api/v1/games/:game_id/ws`
+//!
+//! # Authentication
+//!
+//! The WebSocket upgrade request can't carry an `Authorization` header from
+//! a browser `WebSocket` client, so the token is accepted either as a
+//! `?token=<jwt>` query parameter or as the first text message sent after
+//! the socket opens. Either way it's the same signed `Claims` used by the
+//! rest of the API. A holder who isn't a player in the game is still let in
+//! as a read-only spectator (e.g. a friend watching, or a player who just
+//! finished) rather than rejected outright - only an invalid or revoked
+//! token fails the upgrade.
+//!
+//! A spectator can instead present a `?macaroon=<json>` capability token
+//! (see [`crate::auth::macaroon`]), typically attenuated with
+//! `Caveat::GameId(game_id)` and `Caveat::Scope(Scope::ViewOnly)`, so the
+//! spectator never needs an account on this server at all. The macaroon's
+//! chain must start from `AppState::macaroon_root_key`; nothing in this
+//! module issues macaroons or derives one from a JWT access token. A
+//! presented macaroon always connects as a spectator - see
+//! [`validate_game_macaroon`] for how its scope caveat is checked.
+//!
+//! # Enabling
+//!
+//! Gated behind `AppConfig::websocket.enabled` (mirrors the `ENABLE_WEBSOCKET`
+//! toggle other game servers expose) so deployments that only want the plain
+//! HTTP surface can leave it off with zero behavior change.
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, Query, State};
+use axum::response::{IntoResponse, Response};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::auth::macaroon::{Macaroon, RequestContext, Scope};
+use crate::auth::Claims;
+use crate::AppState;
+use jsonwebtoken::{decode, DecodingKey, Validation};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Maximum number of buffered events per game before slow subscribers start
+/// missing messages (mirrors `tokio::sync::broadcast`'s own backpressure
+/// model: a lagging receiver skips ahead rather than blocking publishers).
+const GAME_EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Per-game fan-out registry for WebSocket broadcast channels.
+///
+/// Keyed by `game_id` so each game gets its own independent channel; a
+/// burst of draws in one game never backs up delivery to another.
+#[derive(Default)]
+pub struct GameBroadcastRegistry {
+    channels: Mutex<HashMap<Uuid, broadcast::Sender<GameEvent>>>,
+}
+
+impl GameBroadcastRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the sender for `game_id`, creating its channel on first use.
+    pub fn sender(&self, game_id: Uuid) -> broadcast::Sender<GameEvent> {
+        let mut channels = self.channels.lock().expect("broadcast registry poisoned");
+        channels
+            .entry(game_id)
+            .or_insert_with(|| broadcast::channel(GAME_EVENT_CHANNEL_CAPACITY).0)
+            .clone()
+    }
+
+    /// Total number of live subscribers across every game's channel.
+    ///
+    /// Used by the optional `stub_status` introspection endpoints; not on
+    /// any hot path, so a full scan of the registry on each call is fine.
+    #[cfg_attr(not(feature = "stub_status"), allow(dead_code))]
+    pub fn total_subscriber_count(&self) -> usize {
+        self.channels
+            .lock()
+            .expect("broadcast registry poisoned")
+            .values()
+            .map(|sender| sender.receiver_count())
+            .sum()
+    }
+
+    /// Live subscriber count for each game that has an open channel.
+    #[cfg_attr(not(feature = "stub_status"), allow(dead_code))]
+    pub fn subscriber_counts_by_game(&self) -> std::collections::HashMap<Uuid, usize> {
+        self.channels
+            .lock()
+            .expect("broadcast registry poisoned")
+            .iter()
+            .map(|(game_id, sender)| (*game_id, sender.receiver_count()))
+            .collect()
+    }
+
+    /// Publishes `event` to every subscriber of `game_id`.
+    ///
+    /// Returns without error when there are no subscribers yet (matches
+    /// `broadcast::Sender::send`'s semantics: `SendError` only means "nobody
+    /// is listening," which is not a failure from the caller's perspective).
+    pub fn publish(&self, game_id: Uuid, event: GameEvent) {
+        let _ = self.sender(game_id).send(event);
+    }
+}
+
+/// What kind of action triggered a [`GameEvent`].
+///
+/// Serialized in `snake_case` as [`GameEvent`]'s `kind` field, so clients
+/// can dispatch on that single field without parsing the rest of the
+/// payload first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameEventKind {
+    /// A player drew a card.
+    CardDrawn,
+    /// A player changed an Ace between 1 and 11 points.
+    AceValueChanged,
+    /// Turn advanced to the next player (including a player standing).
+    TurnChanged,
+    /// The game finished and results are final.
+    GameFinished,
+}
+
+/// A single state-changing action fanned out to connected clients.
+///
+/// Wraps the full game state so clients never need to reconcile a partial
+/// diff against what they already have.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameEvent {
+    /// What kind of action triggered this push.
+    pub kind: GameEventKind,
+
+    /// The player whose action triggered the event, if any.
+    pub actor_email: Option<String>,
+
+    /// Full game state after the action was applied.
+    pub state: blackjack_service::GameStateResponse,
+}
+
+/// Query parameters accepted on the WebSocket upgrade request.
+#[derive(Debug, Deserialize)]
+pub struct WsAuthQuery {
+    /// JWT access token, used when the client can't set an `Authorization`
+    /// header (e.g. a browser `WebSocket` constructor).
+    pub token: Option<String>,
+
+    /// A macaroon capability token (JSON-encoded), as an alternative to
+    /// `token` for spectators who don't hold an account on this server.
+    pub macaroon: Option<String>,
+}
+
+/// Upgrades an HTTP connection to a WebSocket pushing live state for
+/// `game_id`.
+///
+/// # Endpoint
+///
+/// `GET /api/v1/games/:game_id/ws?token=<jwt>`
+///
+/// # Authentication
+///
+/// The token is validated exactly like the `Authorization: Bearer` header on
+/// every other protected endpoint, just carried in the query string (or, if
+/// omitted, expected as the first text frame sent by the client). A holder
+/// who isn't a player in `game_id` connects as a read-only spectator rather
+/// than being rejected - see [`validate_game_token`].
+///
+/// # Errors
+///
+/// - **401 Unauthorized** - invalid, expired, or revoked query token or
+///   macaroon (with neither, the upgrade succeeds and the token is read
+///   from the first text frame instead)
+#[tracing::instrument(skip(state, ws))]
+pub async fn game_ws_handler(
+    State(state): State<AppState>,
+    Path(game_id): Path<Uuid>,
+    Query(query): Query<WsAuthQuery>,
+    ws: WebSocketUpgrade,
+) -> Result<Response, crate::error::ApiError> {
+    if !state.config.websocket.enabled {
+        return Err(crate::error::ApiError::new(
+            axum::http::StatusCode::NOT_FOUND,
+            "WEBSOCKET_DISABLED",
+            "Real-time WebSocket updates are disabled on this server",
+        ));
+    }
+
+    let (claims, is_spectator) = match (query.token, query.macaroon) {
+        (Some(token), _) => validate_game_token(&state, &token, game_id)?,
+        (None, Some(macaroon)) => validate_game_macaroon(&state, &macaroon, game_id)?,
+        // Neither query credential: defer authentication to the first
+        // frame, handled inside `handle_socket_with_handshake` before any
+        // events are forwarded.
+        (None, None) => {
+            return Ok(ws.on_upgrade(move |socket| handle_socket_with_handshake(socket, state, game_id)));
+        }
+    };
+
+    tracing::info!(game_id = %game_id, email = %claims.email, is_spectator, "WebSocket client authenticated via query token");
+
+    Ok(ws.on_upgrade(move |socket| handle_socket(socket, state, game_id, claims, is_spectator)))
+}
+
+/// Decodes and validates a JWT, additionally checking whether its holder is
+/// a member of `expected_game_id`.
+///
+/// Access tokens are account-scoped rather than game-scoped (see
+/// [`crate::auth::Claims`]), so membership is checked against live game
+/// state instead of a claim on the token itself. A non-member isn't
+/// rejected: this connection is push-only (clients never submit moves over
+/// it), so a non-player simply watches as a spectator. Returns whether the
+/// caller is a spectator alongside their claims.
+fn validate_game_token(
+    state: &AppState,
+    token: &str,
+    expected_game_id: Uuid,
+) -> Result<(Claims, bool), crate::error::ApiError> {
+    let data = decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(state.config.jwt.secret.as_bytes()),
+        &Validation::default(),
+    )
+    .map_err(|_| {
+        crate::error::ApiError::new(
+            axum::http::StatusCode::UNAUTHORIZED,
+            "INVALID_TOKEN",
+            "Missing or invalid authentication token",
+        )
+    })?;
+
+    if state.revoked_tokens.is_revoked(&data.claims.jti) {
+        return Err(crate::error::ApiError::new(
+            axum::http::StatusCode::UNAUTHORIZED,
+            "TOKEN_REVOKED",
+            "This token has been revoked",
+        ));
+    }
+
+    let game_state = state.game_service.get_game_state(expected_game_id)?;
+    let is_spectator = !game_state.players.contains_key(&data.claims.email);
+
+    Ok((data.claims, is_spectator))
+}
+
+/// Verifies a macaroon capability token presented as `?macaroon=<json>` and
+/// admits its holder as a spectator for `expected_game_id`.
+///
+/// Unlike [`validate_game_token`], a macaroon holder is never looked up
+/// against live game membership - the macaroon's own `GameId` caveat is what
+/// scopes it to this game. Verification is requested at `Scope::ViewOnly`,
+/// matching what this push-only endpoint actually needs, so a macaroon
+/// attenuated down to `Caveat::Scope(Scope::ViewOnly)` verifies here, and
+/// so does one carrying a `Scope::Play` caveat (`Play` grants `ViewOnly`
+/// requests too). Either way a connection admitted through this path is
+/// always treated as a spectator. The synthesized `Claims`
+/// exist only so the rest of this module's logging and event plumbing,
+/// which expects `Claims`, doesn't need a separate code path for
+/// macaroon-authenticated connections.
+fn validate_game_macaroon(
+    state: &AppState,
+    raw: &str,
+    expected_game_id: Uuid,
+) -> Result<(Claims, bool), crate::error::ApiError> {
+    let unauthorized = || {
+        crate::error::ApiError::new(
+            axum::http::StatusCode::UNAUTHORIZED,
+            "INVALID_TOKEN",
+            "Missing or invalid authentication token",
+        )
+    };
+
+    let macaroon: Macaroon = serde_json::from_str(raw).map_err(|_| unauthorized())?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock before UNIX epoch")
+        .as_secs() as usize;
+
+    let ctx = RequestContext {
+        game_id: expected_game_id.to_string(),
+        requested_scope: Scope::ViewOnly,
+        now,
+    };
+
+    macaroon.verify(&state.macaroon_root_key, &ctx).map_err(|_| unauthorized())?;
+
+    let claims = Claims {
+        user_id: String::new(),
+        email: macaroon.identifier.clone(),
+        jti: format!("macaroon:{}", Uuid::new_v4()),
+        exp: now,
+    };
+
+    Ok((claims, true))
+}
+
+/// Handles a socket that was upgraded without a query-param token: the first
+/// text frame sent by the client must be the JWT, after which the connection
+/// behaves exactly like `handle_socket`.
+async fn handle_socket_with_handshake(mut socket: WebSocket, state: AppState, game_id: Uuid) {
+    let Some(Ok(Message::Text(token))) = socket.recv().await else {
+        let _ = socket
+            .send(Message::Text("{\"error\":\"expected auth token as first message\"}".into()))
+            .await;
+        return;
+    };
+
+    match validate_game_token(&state, &token, game_id) {
+        Ok((claims, is_spectator)) => handle_socket(socket, state, game_id, claims, is_spectator).await,
+        Err(_) => {
+            let _ = socket
+                .send(Message::Text("{\"error\":\"invalid token\"}".into()))
+                .await;
+        }
+    }
+}
+
+/// Drives a single authenticated WebSocket connection: subscribes to the
+/// game's broadcast channel and forwards every event as a JSON text frame
+/// until the client disconnects. `is_spectator` callers never have moves to
+/// submit, so there's no behavioral branch here - it only affects logging.
+async fn handle_socket(
+    mut socket: WebSocket,
+    state: AppState,
+    game_id: Uuid,
+    claims: Claims,
+    is_spectator: bool,
+) {
+    let mut receiver = state.game_broadcast.sender(game_id).subscribe();
+
+    tracing::info!(game_id = %game_id, email = %claims.email, is_spectator, "WebSocket client connected");
+
+    loop {
+        tokio::select! {
+            event = receiver.recv() => {
+                match event {
+                    Ok(event) => {
+                        let payload = match serde_json::to_string(&event) {
+                            Ok(payload) => payload,
+                            Err(err) => {
+                                tracing::error!(error = ?err, "Failed to serialize game event");
+                                continue;
+                            }
+                        };
+                        if socket.send(Message::Text(payload)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!(game_id = %game_id, skipped, "WebSocket client lagged, events dropped");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            incoming = socket.recv() => {
+                match incoming {
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Err(_)) => break,
+                    // Clients aren't expected to send anything after the
+                    // handshake; ignore pings/pongs/stray text frames.
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    tracing::info!(game_id = %game_id, email = %claims.email, is_spectator, "WebSocket client disconnected");
+}